@@ -1,8 +1,9 @@
 //! the folded stuff, ready to be reduced.
 use crate::folders::Map;
+use crate::instrumentation::{Instrumented, InstrumentedFolder, SchedulerHooks};
 use crate::prelude::*;
-use crate::scheduling::{fold_with_help, schedule};
-use crate::traits::{BasicPower, BlockedOrMore};
+use crate::scheduling::{fold_with_help, schedule, OrderedScheduler, Scheduler};
+use crate::traits::{divide_checked, BasicPower, BlockedOrMore};
 use crate::{DivisibleIntoBlocks, Folder, Policy};
 use std::cmp::min;
 use std::collections::linked_list;
@@ -60,6 +61,45 @@ impl<F: Folder, S, P> ActivatedInput<F, S, P> {
             power: self.power,
         }
     }
+
+    /// Install given hooks for this run.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// use rayon_adaptive::SchedulerHooks;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// struct BlocksCounter(AtomicUsize);
+    /// impl SchedulerHooks for BlocksCounter {
+    ///     fn on_block_start(&self, _size: usize) {
+    ///         self.0.fetch_add(1, Ordering::Relaxed);
+    ///     }
+    /// }
+    /// let counter = BlocksCounter(AtomicUsize::new(0));
+    /// let s = (0..10_000)
+    ///     .into_adapt_iter()
+    ///     .fold(|| 0, |s, e| s + e)
+    ///     .with_hooks(&counter)
+    ///     .reduce(|a, b| a + b);
+    /// assert_eq!(s, 9_999 * 5_000);
+    /// assert!(counter.0.load(Ordering::Relaxed) > 0);
+    /// ```
+    pub fn with_hooks<'a, H: SchedulerHooks + ?Sized>(
+        self,
+        hooks: &'a H,
+    ) -> ActivatedInput<InstrumentedFolder<'a, F, H>, S, P> {
+        ActivatedInput {
+            input: Instrumented::new(self.input, hooks),
+            folder: InstrumentedFolder {
+                inner_folder: self.folder,
+                hooks,
+            },
+            policy: self.policy,
+            sizes: self.sizes,
+            power: self.power,
+        }
+    }
 }
 
 impl<F> ActivatedInput<F, Empty<usize>, BasicPower>
@@ -67,12 +107,35 @@ where
     F: Folder,
     F::Input: Divisible<Power = BasicPower>,
 {
+    /// Reduce with our policy.
+    /// Outputs are combined in input order.
     pub fn reduce<RF: Fn(F::Output, F::Output) -> F::Output + Sync>(
         self,
         reduce_function: RF,
     ) -> F::Output {
-        let (input, folder, policy) = (self.input, self.folder, self.policy);
-        schedule(input, &folder, &reduce_function, policy)
+        let policy = self.policy;
+        self.reduce_ordered_with(&policy, reduce_function)
+    }
+
+    /// Reduce using given scheduler instead of our policy.
+    /// Outputs might be combined in any order, see `reduce_ordered_with`.
+    pub fn reduce_with<SC, RF>(self, scheduler: &SC, reduce_function: RF) -> F::Output
+    where
+        SC: Scheduler,
+        RF: Fn(F::Output, F::Output) -> F::Output + Sync,
+    {
+        let (input, folder) = (self.input, self.folder);
+        scheduler.schedule(input, &folder, &reduce_function)
+    }
+
+    /// Reduce using given ordered scheduler.
+    /// Outputs are always combined in input order.
+    pub fn reduce_ordered_with<SC, RF>(self, scheduler: &SC, reduce_function: RF) -> F::Output
+    where
+        SC: OrderedScheduler,
+        RF: Fn(F::Output, F::Output) -> F::Output + Sync,
+    {
+        self.reduce_with(scheduler, reduce_function)
     }
 }
 
@@ -82,23 +145,69 @@ where
     F::Input: DivisibleIntoBlocks,
     S: Iterator<Item = usize>,
 {
+    /// Reduce with our policy.
+    /// Outputs are combined in input order.
     pub fn reduce<RF: Fn(F::Output, F::Output) -> F::Output + Sync>(
         self,
         reduce_function: RF,
     ) -> F::Output {
-        let (input, folder, policy, sizes) = (self.input, self.folder, self.policy, self.sizes);
+        let policy = self.policy;
+        self.reduce_ordered_with(&policy, reduce_function)
+    }
+
+    /// Reduce using given scheduler instead of our policy.
+    /// The scheduler is called on each macro block.
+    /// Macro blocks outputs are reduced in order but inside each block
+    /// outputs might be combined in any order, see `reduce_ordered_with`.
+    pub fn reduce_with<SC, RF>(self, scheduler: &SC, reduce_function: RF) -> F::Output
+    where
+        SC: Scheduler,
+        RF: Fn(F::Output, F::Output) -> F::Output + Sync,
+    {
+        let (input, folder, sizes) = (self.input, self.folder, self.sizes);
         let reduce_ref = &reduce_function;
         let length = input.base_length();
+        if length == 0 {
+            // no chunks to reduce
+            return folder.to_output(folder.identity(), input);
+        }
         let mut outputs = input
             .chunks(sizes.chain(once(length)))
-            .map(|input| schedule(input, &folder, reduce_ref, policy));
+            .map(|input| scheduler.schedule(input, &folder, reduce_ref));
         let first_output = outputs.next().unwrap();
         outputs.fold(first_output, reduce_ref)
     }
+
+    /// Reduce using given ordered scheduler.
+    /// Outputs are always combined in input order, so `reduce_function`
+    /// only needs to be associative.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// use rayon_adaptive::Policy;
+    /// let s = (0..1_000)
+    ///     .into_adapt_iter()
+    ///     .fold(String::new, |mut s, e| {
+    ///         s.push_str(&e.to_string());
+    ///         s
+    ///     })
+    ///     .reduce_ordered_with(&Policy::Join(10), |a, b| a + &b);
+    /// assert_eq!(s, (0..1_000).map(|e| e.to_string()).collect::<String>());
+    /// ```
+    pub fn reduce_ordered_with<SC, RF>(self, scheduler: &SC, reduce_function: RF) -> F::Output
+    where
+        SC: OrderedScheduler,
+        RF: Fn(F::Output, F::Output) -> F::Output + Sync,
+    {
+        self.reduce_with(scheduler, reduce_function)
+    }
 }
 
 pub struct OutputIterator<F: Folder, S> {
-    remaining_input: F::Input,
+    // only empty while dividing
+    remaining_input: Option<F::Input>,
     folder: Map<F, LinkedList<F::Output>, fn(F::Output) -> LinkedList<F::Output>>,
     sizes: S,
     policy: Policy,
@@ -115,7 +224,7 @@ impl<F: Folder, S: Iterator<Item = usize>> OutputIterator<F, Chain<S, Once<usize
         let length = input.base_length();
 
         OutputIterator {
-            remaining_input: input,
+            remaining_input: Some(input),
             folder: folder.map(into_list),
             sizes: sizes.chain(once(length)),
             policy,
@@ -138,14 +247,18 @@ where
                 return possible_next;
             }
         }
-        if self.remaining_input.base_length() == 0 {
+        let remaining_input = self.remaining_input.take()?;
+        let length = remaining_input.base_length();
+        if length == 0 {
+            self.remaining_input = Some(remaining_input);
             None
         } else {
             let next_size = min(
                 self.sizes.next().expect("not enough sizes for chunks"),
-                self.remaining_input.base_length(),
+                length,
             );
-            let next_chunk = self.remaining_input.cut_left_at(next_size);
+            let (next_chunk, remaining_input) = divide_checked(remaining_input, next_size);
+            self.remaining_input = Some(remaining_input);
             let outputs_list = schedule(
                 next_chunk,
                 &self.folder,
@@ -215,7 +328,7 @@ impl<
         let (input, folder, sizes, policy) = (self.input, self.folder, self.sizes, self.policy);
         let f_ref = &f;
         let master_fold = |_: (), i: I, size: usize| -> ((), I) {
-            let (todo, remaining) = i.divide_at(size);
+            let (todo, remaining) = divide_checked(i, size);
             todo.into_iter().for_each(f_ref);
             ((), remaining)
         };
@@ -253,7 +366,7 @@ impl<I: DivisibleIntoBlocks, F: Folder<Input = I> + Send, S: Iterator<Item = usi
     {
         let (input, folder, sizes, policy) = (self.input, self.folder, self.sizes, self.policy);
         let cutting_fold = |io, i: I, limit| {
-            let (todo, remaining) = i.divide_at(limit);
+            let (todo, remaining) = divide_checked(i, limit);
             (f(io, todo), remaining)
         };
         fold_with_help(input, init, cutting_fold, &folder, retrieve, sizes, policy)
@@ -272,7 +385,7 @@ impl<I: AdaptiveIterator, F: Folder<Input = I> + Send, S: Iterator<Item = usize>
         let (input, folder, sizes, policy) = (self.input, self.folder, self.sizes, self.policy);
         let f_ref = &f;
         let sequential_fold = |io, i: I, limit| {
-            let (todo, remaining) = i.divide_at(limit);
+            let (todo, remaining) = divide_checked(i, limit);
             (todo.into_iter().fold(io, f_ref), remaining)
         };
         fold_with_help(
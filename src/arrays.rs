@@ -0,0 +1,113 @@
+//! ndarray integration: divide array views along a chosen axis.
+use crate::traits::IndexedPower;
+use crate::{Divisible, DivisibleAtIndex, DivisibleIntoBlocks};
+use ndarray::{ArrayView, ArrayViewMut, Axis, Dimension};
+
+/// Array view divided along one of its axes.
+///
+/// # Example
+///
+/// ```
+/// use ndarray::{Array2, Axis};
+/// use rayon_adaptive::arrays::AxisView;
+/// use rayon_adaptive::prelude::*;
+/// let a = Array2::from_shape_fn((1_000, 10), |(i, j)| i * j);
+/// // sum of each row, rows being processed in parallel
+/// let sums: Vec<usize> = AxisView::new(a.view(), Axis(0))
+///     .map_reduce(
+///         |rows| {
+///             rows.view()
+///                 .axis_iter(Axis(0))
+///                 .map(|row| row.sum())
+///                 .collect::<Vec<usize>>()
+///         },
+///         |mut left, right| {
+///             left.extend(right);
+///             left
+///         },
+///     );
+/// assert_eq!(sums[3], 3 * 45);
+/// ```
+pub struct AxisView<'a, A, D: Dimension> {
+    view: ArrayView<'a, A, D>,
+    axis: Axis,
+}
+
+impl<'a, A, D: Dimension> AxisView<'a, A, D> {
+    /// Divide given view along given axis.
+    pub fn new(view: ArrayView<'a, A, D>, axis: Axis) -> Self {
+        AxisView { view, axis }
+    }
+    /// Return the viewed part of the array.
+    pub fn view(&self) -> &ArrayView<'a, A, D> {
+        &self.view
+    }
+    /// Return the viewed part of the array.
+    pub fn into_inner(self) -> ArrayView<'a, A, D> {
+        self.view
+    }
+}
+
+impl<'a, A: Sync, D: Dimension> Divisible for AxisView<'a, A, D> {
+    type Power = IndexedPower;
+    fn base_length(&self) -> usize {
+        self.view.len_of(self.axis)
+    }
+    fn divide(self) -> (Self, Self) {
+        let mid = self.base_length() / 2;
+        self.divide_at(mid)
+    }
+}
+
+impl<'a, A: Sync, D: Dimension> DivisibleIntoBlocks for AxisView<'a, A, D> {
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        let axis = self.axis;
+        let (left, right) = self.view.split_at(axis, index);
+        (AxisView::new(left, axis), AxisView::new(right, axis))
+    }
+}
+
+impl<'a, A: Sync, D: Dimension> DivisibleAtIndex for AxisView<'a, A, D> {}
+
+/// Mutable array view divided along one of its axes.
+/// See `AxisView`.
+pub struct AxisViewMut<'a, A, D: Dimension> {
+    view: ArrayViewMut<'a, A, D>,
+    axis: Axis,
+}
+
+impl<'a, A, D: Dimension> AxisViewMut<'a, A, D> {
+    /// Divide given view along given axis.
+    pub fn new(view: ArrayViewMut<'a, A, D>, axis: Axis) -> Self {
+        AxisViewMut { view, axis }
+    }
+    /// Return the viewed part of the array.
+    pub fn view_mut(&mut self) -> &mut ArrayViewMut<'a, A, D> {
+        &mut self.view
+    }
+    /// Return the viewed part of the array.
+    pub fn into_inner(self) -> ArrayViewMut<'a, A, D> {
+        self.view
+    }
+}
+
+impl<'a, A: Send + Sync, D: Dimension> Divisible for AxisViewMut<'a, A, D> {
+    type Power = IndexedPower;
+    fn base_length(&self) -> usize {
+        self.view.len_of(self.axis)
+    }
+    fn divide(self) -> (Self, Self) {
+        let mid = self.base_length() / 2;
+        self.divide_at(mid)
+    }
+}
+
+impl<'a, A: Send + Sync, D: Dimension> DivisibleIntoBlocks for AxisViewMut<'a, A, D> {
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        let axis = self.axis;
+        let (left, right) = self.view.split_at(axis, index);
+        (AxisViewMut::new(left, axis), AxisViewMut::new(right, axis))
+    }
+}
+
+impl<'a, A: Send + Sync, D: Dimension> DivisibleAtIndex for AxisViewMut<'a, A, D> {}
@@ -0,0 +1,52 @@
+//! bitvec integration: divide bit slices on bit indices.
+use crate::traits::IndexedPower;
+use crate::{Divisible, DivisibleAtIndex, DivisibleIntoBlocks};
+use bitvec::order::BitOrder;
+use bitvec::slice::BitSlice;
+use bitvec::store::BitStore;
+
+/// Bit slices are divisible on any bit index.
+/// Mutable bit slices are not since splitting them changes their storage type.
+///
+/// # Example
+///
+/// ```
+/// use bitvec::prelude::*;
+/// use rayon_adaptive::prelude::*;
+/// let bits: BitVec = (0..10_000).map(|i| i % 3 == 0).collect();
+/// let ones = bits
+///     .as_bitslice()
+///     .map_reduce(|bits| bits.count_ones(), |a, b| a + b);
+/// assert_eq!(ones, 3_334);
+/// ```
+impl<T, O> Divisible for &BitSlice<T, O>
+where
+    T: BitStore + Sync,
+    O: BitOrder,
+{
+    type Power = IndexedPower;
+    fn base_length(&self) -> usize {
+        self.len()
+    }
+    fn divide(self) -> (Self, Self) {
+        let mid = self.len() / 2;
+        self.split_at(mid)
+    }
+}
+
+impl<T, O> DivisibleIntoBlocks for &BitSlice<T, O>
+where
+    T: BitStore + Sync,
+    O: BitOrder,
+{
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        self.split_at(index)
+    }
+}
+
+impl<T, O> DivisibleAtIndex for &BitSlice<T, O>
+where
+    T: BitStore + Sync,
+    O: BitOrder,
+{
+}
@@ -0,0 +1,97 @@
+//! Ordered maps, divided by key ranges.
+use crate::traits::IndexedPower;
+use crate::{Divisible, DivisibleAtIndex, DivisibleIntoBlocks};
+use std::collections::btree_map;
+use std::collections::BTreeMap;
+use std::iter::Take;
+use std::ops::Bound;
+
+/// Range of keys of a `BTreeMap`.
+/// Dividing iterates to find the pivot key so it costs linear time in the range's length,
+/// which is still much cheaper than collecting all the keys first.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::prelude::*;
+/// use rayon_adaptive::BTreeRange;
+/// use std::collections::BTreeMap;
+/// let map: BTreeMap<u64, u64> = (0..10_000).map(|i| (i, 2 * i)).collect();
+/// let s: u64 = BTreeRange::new(&map)
+///     .into_adapt_iter()
+///     .map(|(_, v)| *v)
+///     .sum();
+/// assert_eq!(s, 9_999 * 10_000);
+/// ```
+pub struct BTreeRange<'a, K, V> {
+    map: &'a BTreeMap<K, V>,
+    start: Bound<&'a K>,
+    end: Bound<&'a K>,
+    length: usize,
+}
+
+impl<'a, K: Ord, V> BTreeRange<'a, K, V> {
+    /// Range of all keys in given map.
+    pub fn new(map: &'a BTreeMap<K, V>) -> Self {
+        BTreeRange {
+            map,
+            start: Bound::Unbounded,
+            end: Bound::Unbounded,
+            length: map.len(),
+        }
+    }
+    fn iter(&self) -> Take<btree_map::Range<'a, K, V>> {
+        self.map.range((self.start, self.end)).take(self.length)
+    }
+    // same bounds but nothing inside
+    fn emptied(&self) -> Self {
+        BTreeRange { length: 0, ..*self }
+    }
+}
+
+impl<'a, K: Ord + Sync, V: Sync> Divisible for BTreeRange<'a, K, V> {
+    type Power = IndexedPower;
+    fn base_length(&self) -> usize {
+        self.length
+    }
+    fn divide(self) -> (Self, Self) {
+        let mid = self.length / 2;
+        self.divide_at(mid)
+    }
+}
+
+impl<'a, K: Ord + Sync, V: Sync> DivisibleIntoBlocks for BTreeRange<'a, K, V> {
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        assert!(index <= self.length);
+        if index == 0 {
+            return (self.emptied(), self);
+        }
+        if index == self.length {
+            let empty = self.emptied();
+            return (self, empty);
+        }
+        let (pivot, _) = self.iter().nth(index).expect("btree range too short");
+        (
+            BTreeRange {
+                end: Bound::Excluded(pivot),
+                length: index,
+                ..self
+            },
+            BTreeRange {
+                start: Bound::Included(pivot),
+                length: self.length - index,
+                ..self
+            },
+        )
+    }
+}
+
+impl<'a, K: Ord + Sync, V: Sync> DivisibleAtIndex for BTreeRange<'a, K, V> {}
+
+impl<'a, K: Ord, V> IntoIterator for BTreeRange<'a, K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Take<btree_map::Range<'a, K, V>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
@@ -0,0 +1,118 @@
+//! Chain schedulers: hand small pieces of input over to another scheduler.
+use crate::folders::Folder;
+use crate::scheduling::{OrderedScheduler, Scheduler};
+use crate::traits::Divisible;
+
+/// Scheduler switching to the `next` one for small pieces of input.
+/// See `Policy::then`.
+#[derive(Copy, Clone)]
+pub struct Then<S1, S2> {
+    pub(crate) first: S1,
+    pub(crate) length: usize,
+    pub(crate) next: S2,
+}
+
+impl<S1: Scheduler, S2: Scheduler> Scheduler for Then<S1, S2> {
+    fn schedule<F, RF>(&self, input: F::Input, folder: &F, reduce_function: &RF) -> F::Output
+    where
+        F: Folder,
+        RF: Fn(F::Output, F::Output) -> F::Output + Sync,
+    {
+        let hand_off_folder = HandOffFolder {
+            folder,
+            reduce_function,
+            length: self.length,
+            next: &self.next,
+        };
+        self.first
+            .schedule(HandOff(Some(input)), &hand_off_folder, reduce_function)
+    }
+}
+
+impl<S1: OrderedScheduler, S2: OrderedScheduler> OrderedScheduler for Then<S1, S2> {}
+
+// Input which becomes empty once handed over to the next scheduler.
+struct HandOff<I>(Option<I>);
+
+impl<I: Divisible> Divisible for HandOff<I> {
+    type Power = I::Power;
+    fn base_length(&self) -> usize {
+        self.0.as_ref().map_or(0, |i| i.base_length())
+    }
+    fn divide(self) -> (Self, Self) {
+        match self.0 {
+            Some(input) => {
+                let (left, right) = input.divide();
+                (HandOff(Some(left)), HandOff(Some(right)))
+            }
+            None => (HandOff(None), HandOff(None)),
+        }
+    }
+}
+
+enum HandOffOutput<IO, O> {
+    // nothing folded yet
+    Fresh,
+    // folded by the first scheduler
+    Partial(IO),
+    // everything done by the next scheduler
+    Done(O),
+}
+
+// Folder handing small fresh inputs over to the next scheduler.
+// We can only hand over inputs we did not start folding since the inner
+// folder needs the remaining input to produce its output.
+struct HandOffFolder<'a, F, RF, S> {
+    folder: &'a F,
+    reduce_function: &'a RF,
+    length: usize,
+    next: &'a S,
+}
+
+impl<'a, F, RF, S> Folder for HandOffFolder<'a, F, RF, S>
+where
+    F: Folder,
+    RF: Fn(F::Output, F::Output) -> F::Output + Sync,
+    S: Scheduler,
+{
+    type Input = HandOff<F::Input>;
+    type IntermediateOutput = HandOffOutput<F::IntermediateOutput, F::Output>;
+    type Output = F::Output;
+    fn identity(&self) -> Self::IntermediateOutput {
+        HandOffOutput::Fresh
+    }
+    fn fold(
+        &self,
+        io: Self::IntermediateOutput,
+        i: Self::Input,
+        limit: usize,
+    ) -> (Self::IntermediateOutput, Self::Input) {
+        match (io, i.0) {
+            (HandOffOutput::Fresh, Some(input)) => {
+                if input.base_length() <= self.length {
+                    let output = self.next.schedule(input, self.folder, self.reduce_function);
+                    (HandOffOutput::Done(output), HandOff(None))
+                } else {
+                    let (io, input) = self.folder.fold(self.folder.identity(), input, limit);
+                    (HandOffOutput::Partial(io), HandOff(Some(input)))
+                }
+            }
+            (HandOffOutput::Partial(io), Some(input)) => {
+                let (io, input) = self.folder.fold(io, input, limit);
+                (HandOffOutput::Partial(io), HandOff(Some(input)))
+            }
+            (io, None) => (io, HandOff(None)),
+            (HandOffOutput::Done(_), Some(_)) => unreachable!("input was handed over"),
+        }
+    }
+    fn to_output(&self, io: Self::IntermediateOutput, i: Self::Input) -> Self::Output {
+        match (io, i.0) {
+            (HandOffOutput::Done(output), _) => output,
+            (HandOffOutput::Fresh, Some(input)) => {
+                self.folder.to_output(self.folder.identity(), input)
+            }
+            (HandOffOutput::Partial(io), Some(input)) => self.folder.to_output(io, input),
+            (_, None) => unreachable!("input was handed over without output"),
+        }
+    }
+}
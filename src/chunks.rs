@@ -1,25 +1,31 @@
 use std::cmp::min;
 ///! macro loop on input.
+use crate::traits::divide_checked;
 use crate::DivisibleIntoBlocks;
 
 pub struct Chunks<I: DivisibleIntoBlocks, S: Iterator<Item = usize>> {
-    pub(crate) remaining: I,
+    // only empty while dividing
+    pub(crate) remaining: Option<I>,
     pub(crate) remaining_sizes: S,
 }
 
 impl<I: DivisibleIntoBlocks, S: Iterator<Item = usize>> Iterator for Chunks<I, S> {
     type Item = I;
     fn next(&mut self) -> Option<Self::Item> {
-        if self.remaining.base_length() == 0 {
+        let remaining = self.remaining.take()?;
+        let length = remaining.base_length();
+        if length == 0 {
+            self.remaining = Some(remaining);
             None
         } else {
             let next_size = min(
                 self.remaining_sizes
                     .next()
                     .expect("not enough sizes for chunks"),
-                self.remaining.base_length(),
+                length,
             );
-            let next_chunk = self.remaining.cut_left_at(next_size);
+            let (next_chunk, remaining) = divide_checked(remaining, next_size);
+            self.remaining = Some(remaining);
             Some(next_chunk)
         }
     }
@@ -0,0 +1,179 @@
+//! Concatenation of two inputs, processed as one.
+use crate::iter::{AdaptiveIndexedIterator, AdaptiveIterator};
+use crate::{Divisible, DivisibleAtIndex, DivisibleIntoBlocks};
+use std::collections::VecDeque;
+use std::iter;
+use std::option;
+
+/// Two inputs placed back-to-back.
+/// Divisions happen at mid length, inside the first or the second input
+/// or right at the boundary, so pieces stay balanced whatever the inputs lengths.
+/// A part is `None` once divided away.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::prelude::*;
+/// use rayon_adaptive::Chain;
+/// let header: Vec<u32> = (0..10).collect();
+/// let body: Vec<u32> = (10..10_000).collect();
+/// let sum: u32 = Chain::new(header.as_slice(), body.as_slice())
+///     .into_adapt_iter()
+///     .sum();
+/// assert_eq!(sum, 9_999 * 5_000);
+/// let (left, right) = Chain::new(&header[..1], body.as_slice()).divide();
+/// assert_eq!((left.base_length(), right.base_length()), (4_995, 4_996));
+/// ```
+pub struct Chain<A, B> {
+    pub(crate) first: Option<A>,
+    pub(crate) second: Option<B>,
+}
+
+impl<A, B> Chain<A, B> {
+    /// Concatenate given inputs.
+    pub fn new(first: A, second: B) -> Self {
+        Chain {
+            first: Some(first),
+            second: Some(second),
+        }
+    }
+    /// Return what remains of both inputs.
+    pub fn into_parts(self) -> (Option<A>, Option<B>) {
+        (self.first, self.second)
+    }
+}
+
+/// Both slices of a ring buffer.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::prelude::*;
+/// use rayon_adaptive::Chain;
+/// use std::collections::VecDeque;
+/// let mut deque: VecDeque<u64> = (1..10_000).collect();
+/// deque.push_front(0);
+/// Chain::from(&mut deque).into_adapt_iter().for_each(|e| *e *= 2);
+/// let sum: u64 = Chain::from(&deque).into_adapt_iter().sum();
+/// assert_eq!(sum, 9_999 * 10_000);
+/// ```
+impl<'a, T> From<&'a VecDeque<T>> for Chain<&'a [T], &'a [T]> {
+    fn from(deque: &'a VecDeque<T>) -> Self {
+        let (first, second) = deque.as_slices();
+        Chain::new(first, second)
+    }
+}
+
+/// Both slices of a ring buffer, mutably.
+impl<'a, T> From<&'a mut VecDeque<T>> for Chain<&'a mut [T], &'a mut [T]> {
+    fn from(deque: &'a mut VecDeque<T>) -> Self {
+        let (first, second) = deque.as_mut_slices();
+        Chain::new(first, second)
+    }
+}
+
+impl<A: Divisible, B: Divisible> Chain<A, B> {
+    fn first_length(&self) -> usize {
+        self.first.as_ref().map_or(0, |a| a.base_length())
+    }
+    fn second_length(&self) -> usize {
+        self.second.as_ref().map_or(0, |b| b.base_length())
+    }
+}
+
+impl<A, B> Divisible for Chain<A, B>
+where
+    A: DivisibleIntoBlocks,
+    B: DivisibleIntoBlocks<Power = A::Power>,
+{
+    type Power = A::Power;
+    fn base_length(&self) -> usize {
+        self.first_length() + self.second_length()
+    }
+    fn divide(self) -> (Self, Self) {
+        let mid = self.base_length() / 2;
+        self.divide_at(mid)
+    }
+}
+
+impl<A, B> DivisibleIntoBlocks for Chain<A, B>
+where
+    A: DivisibleIntoBlocks,
+    B: DivisibleIntoBlocks<Power = A::Power>,
+{
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        let first_length = self.first_length();
+        if index <= first_length {
+            let (left, right) = match self.first {
+                Some(a) => {
+                    let (left, right) = a.divide_at(index);
+                    (Some(left), Some(right))
+                }
+                None => (None, None),
+            };
+            (
+                Chain {
+                    first: left,
+                    second: None,
+                },
+                Chain {
+                    first: right,
+                    second: self.second,
+                },
+            )
+        } else {
+            let (left, right) = self
+                .second
+                .expect("dividing past the end of a chain")
+                .divide_at(index - first_length);
+            (
+                Chain {
+                    first: self.first,
+                    second: Some(left),
+                },
+                Chain {
+                    first: None,
+                    second: Some(right),
+                },
+            )
+        }
+    }
+}
+
+impl<A, B> DivisibleAtIndex for Chain<A, B>
+where
+    A: DivisibleAtIndex,
+    B: DivisibleAtIndex<Power = A::Power>,
+{
+}
+
+type PartIter<P> = iter::Flatten<option::IntoIter<P>>;
+
+impl<A, B> IntoIterator for Chain<A, B>
+where
+    A: IntoIterator,
+    B: IntoIterator<Item = A::Item>,
+{
+    type Item = A::Item;
+    type IntoIter = iter::Chain<PartIter<A>, PartIter<B>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.first
+            .into_iter()
+            .flatten()
+            .chain(self.second.into_iter().flatten())
+    }
+}
+
+impl<A, B> AdaptiveIterator for Chain<A, B>
+where
+    A: AdaptiveIterator,
+    B: AdaptiveIterator<Item = A::Item, Power = A::Power>,
+{
+}
+
+impl<A, B> AdaptiveIndexedIterator for Chain<A, B>
+where
+    A: AdaptiveIndexedIterator,
+    B: AdaptiveIndexedIterator<Item = A::Item, Power = A::Power>,
+{
+}
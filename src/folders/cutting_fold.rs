@@ -1,3 +1,4 @@
+use crate::traits::divide_checked;
 use crate::{Divisible, DivisibleIntoBlocks, Folder};
 use std::marker::PhantomData;
 
@@ -28,7 +29,7 @@ where
         i: Self::Input,
         limit: usize,
     ) -> (Self::IntermediateOutput, Self::Input) {
-        let (todo, remaining) = i.divide_at(limit);
+        let (todo, remaining) = divide_checked(i, limit);
         ((self.fold_op)(io, todo), remaining)
     }
     fn to_output(&self, io: Self::IntermediateOutput, _i: Self::Input) -> Self::Output {
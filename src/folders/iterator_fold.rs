@@ -1,11 +1,12 @@
 use crate::iter::AdaptiveIterator;
+use crate::traits::divide_checked;
 use crate::Folder;
 use std::marker::PhantomData;
 
 #[must_use = "folders are lazy and do nothing unless consumed"]
 pub struct AdaptiveIteratorFold<
     I: AdaptiveIterator,
-    IO: Send + Sync + Clone,
+    IO: Send + Sync,
     ID: Fn() -> IO + Send + Sync,
     F: Fn(IO, I::Item) -> IO + Send + Sync,
 > {
@@ -16,7 +17,7 @@ pub struct AdaptiveIteratorFold<
 
 impl<
         I: AdaptiveIterator,
-        IO: Send + Sync + Clone,
+        IO: Send + Sync,
         ID: Fn() -> IO + Send + Sync,
         F: Fn(IO, I::Item) -> IO + Send + Sync,
     > Folder for AdaptiveIteratorFold<I, IO, ID, F>
@@ -35,7 +36,44 @@ impl<
     ) -> (Self::IntermediateOutput, Self::Input) {
         // for now we use the overhead version
         // we could avoid it with a "partial_fold"
-        let (todo, remaining) = i.divide_at(limit);
+        let (todo, remaining) = divide_checked(i, limit);
+        (todo.into_iter().fold(io, &self.fold_op), remaining)
+    }
+    fn to_output(&self, io: Self::IntermediateOutput, _i: Self::Input) -> Self::Output {
+        io
+    }
+}
+
+/// Like `AdaptiveIteratorFold` but starting each task from a clone of `init`.
+/// Each worker keeps folding into the same accumulator while it processes
+/// consecutive sequential blocks: clones only happen when some work gets stolen.
+#[must_use = "folders are lazy and do nothing unless consumed"]
+pub struct AdaptiveIteratorFoldWith<
+    I: AdaptiveIterator,
+    T: Clone + Send + Sync,
+    F: Fn(T, I::Item) -> T + Send + Sync,
+> {
+    pub(crate) init: T,
+    pub(crate) fold_op: F,
+    pub(crate) phantom: PhantomData<I>,
+}
+
+impl<I: AdaptiveIterator, T: Clone + Send + Sync, F: Fn(T, I::Item) -> T + Send + Sync> Folder
+    for AdaptiveIteratorFoldWith<I, T, F>
+{
+    type Input = I;
+    type IntermediateOutput = T;
+    type Output = T;
+    fn identity(&self) -> Self::IntermediateOutput {
+        self.init.clone()
+    }
+    fn fold(
+        &self,
+        io: Self::IntermediateOutput,
+        i: Self::Input,
+        limit: usize,
+    ) -> (Self::IntermediateOutput, Self::Input) {
+        let (todo, remaining) = divide_checked(i, limit);
         (todo.into_iter().fold(io, &self.fold_op), remaining)
     }
     fn to_output(&self, io: Self::IntermediateOutput, _i: Self::Input) -> Self::Output {
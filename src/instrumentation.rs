@@ -0,0 +1,337 @@
+//! Observe how inputs get divided and processed by the schedulers.
+use crate::folders::Folder;
+use crate::traits::{Divisible, DivisibleAtIndex, DivisibleIntoBlocks};
+#[cfg(not(feature = "logs"))]
+use rayon::current_thread_index;
+#[cfg(feature = "logs")]
+use real_rayon::current_thread_index;
+use std::cmp::min;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread::ThreadId;
+use std::time::{Duration, Instant};
+
+/// Callbacks invoked while scheduling an input.
+/// All callbacks default to doing nothing.
+/// Hooks are installed for one run with `with_hooks`.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::prelude::*;
+/// use rayon_adaptive::SchedulerHooks;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::time::Duration;
+/// struct ProcessedCounter(AtomicUsize);
+/// impl SchedulerHooks for ProcessedCounter {
+///     fn on_block_end(&self, size: usize, _duration: Duration) {
+///         self.0.fetch_add(size, Ordering::Relaxed);
+///     }
+/// }
+/// let counter = ProcessedCounter(AtomicUsize::new(0));
+/// let s = (0..10_007u64)
+///     .into_adapt_iter()
+///     .fold(|| 0, |s, e| s + e)
+///     .with_hooks(&counter)
+///     .reduce(|a, b| a + b);
+/// assert_eq!(s, 10_006 * 10_007 / 2);
+/// assert_eq!(counter.0.load(Ordering::Relaxed), 10_007);
+/// ```
+pub trait SchedulerHooks: Sync {
+    /// An input of given length got divided in two.
+    fn on_split(&self, _length: usize) {}
+    /// We start folding sequentially a block of (at most) given size.
+    fn on_block_start(&self, _size: usize) {}
+    /// We are done folding sequentially a block.
+    /// Size is the base length actually processed, which can be less than
+    /// announced by `on_block_start` when the fold stops early.
+    fn on_block_end(&self, _size: usize, _duration: Duration) {}
+    /// An input of given length is processed by another thread than the one
+    /// which divided it.
+    fn on_steal(&self, _length: usize) {}
+    /// Same as `on_split` but with nodes identifiers in the division tree.
+    fn on_node_split(&self, _node: usize, _left: usize, _right: usize, _length: usize) {}
+    /// Same as `on_block_end` but with node identifier in the division tree
+    /// and index of the executing thread. Empty blocks are not reported.
+    fn on_node_block(&self, _node: usize, _size: usize, _thread: Option<usize>) {}
+}
+
+// all instrumented inputs get a unique node identifier
+static NEXT_NODE: AtomicUsize = AtomicUsize::new(0);
+
+fn new_node() -> usize {
+    NEXT_NODE.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Hooks counting what happened during a run.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::prelude::*;
+/// use rayon_adaptive::instrumentation::Statistics;
+/// let statistics = Statistics::new();
+/// let s = (0..100_000u64)
+///     .into_adapt_iter()
+///     .fold(|| 0, |s, e| s + e)
+///     .with_hooks(&statistics)
+///     .reduce(|a, b| a + b);
+/// assert_eq!(s, 99_999 * 50_000);
+/// assert!(statistics.blocks() > 0);
+/// assert!(statistics.steals() <= statistics.splits());
+/// ```
+#[derive(Default)]
+pub struct Statistics {
+    blocks: AtomicUsize,
+    splits: AtomicUsize,
+    steals: AtomicUsize,
+    sequential_times: Mutex<HashMap<ThreadId, Duration>>,
+}
+
+impl Statistics {
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Number of sequential blocks executed.
+    pub fn blocks(&self) -> usize {
+        self.blocks.load(Ordering::SeqCst)
+    }
+    /// Number of divisions.
+    pub fn splits(&self) -> usize {
+        self.splits.load(Ordering::SeqCst)
+    }
+    /// Number of successful steals.
+    pub fn steals(&self) -> usize {
+        self.steals.load(Ordering::SeqCst)
+    }
+    /// Total time spent in sequential blocks by each thread.
+    pub fn sequential_times(&self) -> HashMap<ThreadId, Duration> {
+        self.sequential_times
+            .lock()
+            .expect("statistics lock poisoned")
+            .clone()
+    }
+}
+
+impl SchedulerHooks for Statistics {
+    fn on_split(&self, _length: usize) {
+        self.splits.fetch_add(1, Ordering::SeqCst);
+    }
+    fn on_block_end(&self, _size: usize, duration: Duration) {
+        self.blocks.fetch_add(1, Ordering::SeqCst);
+        *self
+            .sequential_times
+            .lock()
+            .expect("statistics lock poisoned")
+            .entry(std::thread::current().id())
+            .or_default() += duration;
+    }
+    fn on_steal(&self, _length: usize) {
+        self.steals.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+// sizes of executed blocks together with executing threads
+type Blocks = Vec<(usize, Option<usize>)>;
+
+/// Hooks recording the division tree of a run.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::prelude::*;
+/// use rayon_adaptive::instrumentation::DivisionTree;
+/// let tree = DivisionTree::new();
+/// let s = (0..100_000u64)
+///     .into_adapt_iter()
+///     .fold(|| 0, |s, e| s + e)
+///     .with_hooks(&tree)
+///     .reduce(|a, b| a + b);
+/// assert_eq!(s, 99_999 * 50_000);
+/// assert!(tree.to_dot().starts_with("digraph"));
+/// ```
+#[derive(Default)]
+pub struct DivisionTree {
+    // for each divided node its length and children
+    divisions: Mutex<Vec<(usize, usize, usize, usize)>>,
+    // for each node executing blocks, their sizes and threads
+    blocks: Mutex<HashMap<usize, Blocks>>,
+}
+
+impl DivisionTree {
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Return the division tree in graphviz's dot format.
+    /// Nodes are labeled with their lengths and the sizes of the blocks
+    /// they executed together with the executing threads.
+    pub fn to_dot(&self) -> String {
+        let divisions = self.divisions.lock().expect("division tree lock poisoned");
+        let blocks = self.blocks.lock().expect("division tree lock poisoned");
+        let mut dot = String::from("digraph {\n");
+        for &(node, left, right, length) in divisions.iter() {
+            dot.push_str(&format!("    n{} [label=\"{}\"];\n", node, length));
+            dot.push_str(&format!("    n{} -> n{};\n", node, left));
+            dot.push_str(&format!("    n{} -> n{};\n", node, right));
+        }
+        for (node, node_blocks) in blocks.iter() {
+            let label: Vec<String> = node_blocks
+                .iter()
+                .map(|(size, thread)| match thread {
+                    Some(thread) => format!("{} on {}", size, thread),
+                    None => size.to_string(),
+                })
+                .collect();
+            dot.push_str(&format!(
+                "    n{} [shape=box, label=\"{}\"];\n",
+                node,
+                label.join("\\n")
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl SchedulerHooks for DivisionTree {
+    fn on_node_split(&self, node: usize, left: usize, right: usize, length: usize) {
+        self.divisions
+            .lock()
+            .expect("division tree lock poisoned")
+            .push((node, left, right, length))
+    }
+    fn on_node_block(&self, node: usize, size: usize, thread: Option<usize>) {
+        self.blocks
+            .lock()
+            .expect("division tree lock poisoned")
+            .entry(node)
+            .or_default()
+            .push((size, thread))
+    }
+}
+
+/// Divisible input reporting its divisions to some hooks.
+pub struct Instrumented<'a, I, H: ?Sized> {
+    pub(crate) input: I,
+    pub(crate) hooks: &'a H,
+    // thread which last worked on us
+    pub(crate) thread: Option<usize>,
+    // our node in the division tree
+    pub(crate) node: usize,
+}
+
+impl<'a, I, H: SchedulerHooks + ?Sized> Instrumented<'a, I, H> {
+    pub(crate) fn new(input: I, hooks: &'a H) -> Self {
+        Instrumented {
+            input,
+            hooks,
+            thread: current_thread_index(),
+            node: new_node(),
+        }
+    }
+}
+
+impl<'a, I: Divisible, H: SchedulerHooks + ?Sized> Divisible for Instrumented<'a, I, H> {
+    type Power = I::Power;
+    fn base_length(&self) -> usize {
+        self.input.base_length()
+    }
+    fn divide(self) -> (Self, Self) {
+        let length = self.input.base_length();
+        let (left_node, right_node) = (new_node(), new_node());
+        self.hooks.on_split(length);
+        self.hooks
+            .on_node_split(self.node, left_node, right_node, length);
+        let thread = current_thread_index();
+        let (left, right) = self.input.divide();
+        (
+            Instrumented {
+                input: left,
+                hooks: self.hooks,
+                thread,
+                node: left_node,
+            },
+            Instrumented {
+                input: right,
+                hooks: self.hooks,
+                thread,
+                node: right_node,
+            },
+        )
+    }
+}
+
+impl<'a, I: DivisibleIntoBlocks, H: SchedulerHooks + ?Sized> DivisibleIntoBlocks
+    for Instrumented<'a, I, H>
+{
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.input.divide_at(index);
+        (
+            Instrumented {
+                input: left,
+                hooks: self.hooks,
+                thread: self.thread,
+                node: self.node,
+            },
+            Instrumented {
+                input: right,
+                hooks: self.hooks,
+                thread: self.thread,
+                node: self.node,
+            },
+        )
+    }
+}
+
+impl<'a, I: DivisibleAtIndex, H: SchedulerHooks + ?Sized> DivisibleAtIndex
+    for Instrumented<'a, I, H>
+{
+}
+
+/// Folder calling the hooks around each sequential block.
+pub struct InstrumentedFolder<'a, F, H: ?Sized> {
+    pub(crate) inner_folder: F,
+    pub(crate) hooks: &'a H,
+}
+
+impl<'a, F: Folder, H: SchedulerHooks + ?Sized> Folder for InstrumentedFolder<'a, F, H> {
+    type Input = Instrumented<'a, F::Input, H>;
+    type IntermediateOutput = F::IntermediateOutput;
+    type Output = F::Output;
+    fn identity(&self) -> Self::IntermediateOutput {
+        self.inner_folder.identity()
+    }
+    fn fold(
+        &self,
+        io: Self::IntermediateOutput,
+        i: Self::Input,
+        limit: usize,
+    ) -> (Self::IntermediateOutput, Self::Input) {
+        let mut i = i;
+        let thread = current_thread_index();
+        if i.thread.is_some() && i.thread != thread {
+            self.hooks.on_steal(i.input.base_length());
+        }
+        i.thread = thread;
+        let length = i.input.base_length();
+        self.hooks.on_block_start(min(limit, length));
+        let start = Instant::now();
+        let (io, remaining) = self.inner_folder.fold(io, i.input, limit);
+        let processed = length.saturating_sub(remaining.base_length());
+        self.hooks.on_block_end(processed, start.elapsed());
+        if processed > 0 {
+            self.hooks.on_node_block(i.node, processed, thread);
+        }
+        (
+            io,
+            Instrumented {
+                input: remaining,
+                ..i
+            },
+        )
+    }
+    fn to_output(&self, io: Self::IntermediateOutput, i: Self::Input) -> Self::Output {
+        self.inner_folder.to_output(io, i.input)
+    }
+}
@@ -1,91 +1,443 @@
 use crate::prelude::*;
-use crate::traits::BlockedPower;
+use crate::traits::{BlockedPower, IndexedPower};
+use crate::utils::OrderedAbortingDivisible;
+use crate::DivisionError;
+use derive_divisible::{Divisible, DivisibleIntoBlocks};
 use rayon::current_num_threads;
+use std::cmp::max;
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasher, Hash};
 use std::iter::repeat;
-use std::mem;
-pub trait FromAdaptiveBlockedIterator<T>
-where
-    T: Send,
-{
-    fn from_adapt_iter<I, R, S>(runner: R) -> Self
-    where
-        I: AdaptiveIterator<Item = T, Power = BlockedPower>,
-        R: AdaptiveBlockedIteratorRunner<I, S>,
-        S: Iterator<Item = usize>;
-}
+use std::mem::{self, MaybeUninit};
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
 
-pub trait FromAdaptiveIndexedIterator<T>
-where
-    T: Send,
-{
+/// Collections we can build out of adaptive iterators.
+/// See `AdaptiveIteratorRunner::collect`.
+/// `P` is the power of the collected iterators, enabling faster algorithms on
+/// indexed iterators.
+pub trait FromAdaptiveIterator<T: Send, P>: Sized {
     fn from_adapt_iter<I, R, S>(runner: R) -> Self
     where
-        I: AdaptiveIndexedIterator<Item = T>,
-        R: AdaptiveIndexedIteratorRunner<I, S>,
+        I: AdaptiveIterator<Item = T, Power = P>,
+        R: AdaptiveIteratorRunner<I, S>,
         S: Iterator<Item = usize>;
 }
 
 //TODO:
 // 1) we need to test performances for block sizes
 // 2) we still need the fully adaptive algorithm
-// 3) extend in parallel ?
-impl<T: Send + Sync> FromAdaptiveBlockedIterator<T> for Vec<T> {
+impl<T: Send + Sync> FromAdaptiveIterator<T, BlockedPower> for Vec<T> {
     fn from_adapt_iter<I, R, S>(runner: R) -> Self
     where
         I: AdaptiveIterator<Item = T, Power = BlockedPower>,
-        R: AdaptiveBlockedIteratorRunner<I, S>,
+        R: AdaptiveIteratorRunner<I, S>,
         S: Iterator<Item = usize>,
     {
         let (input, policy, sizes) = runner.input_policy_sizes();
-        let capacity = input.base_length();
-        input
+        // each task fills its own vector
+        let mut vectors: Vec<Vec<T>> = input
             .with_policy(policy)
             .by_blocks(sizes.chain(repeat(
                 // let's fit in 1mb cache
-                1_000_000 * current_num_threads() / mem::size_of::<T>(),
+                1_000_000 * current_num_threads() / max(mem::size_of::<T>(), 1),
             )))
-            .partial_fold(
-                move || Vec::with_capacity(capacity),
-                |mut v, i, limit| {
-                    let (todo, remaining) = i.divide_at(limit);
-                    v.extend(todo.into_iter()); // optimized extend, yay !
-                    (v, remaining)
-                },
-            )
+            .partial_fold(Vec::new, |mut v, i, limit| {
+                let (todo, remaining) = i.divide_at(limit);
+                v.extend(todo.into_iter()); // optimized extend, yay !
+                (v, remaining)
+            })
             .into_iter()
-            .fold(None, |final_v: Option<Vec<T>>, v| {
-                if final_v.is_some() {
-                    final_v.map(|mut f| {
-                        f.extend(v);
-                        f
-                    })
-                } else {
-                    Some(v)
-                }
+            .filter(|v| !v.is_empty())
+            .collect();
+        if vectors.len() <= 1 {
+            return vectors.pop().unwrap_or_else(Vec::new);
+        }
+        // now compact all vectors in parallel into the final one
+        let total_length = vectors.iter().map(Vec::len).sum();
+        let mut output_vector = Vec::with_capacity(total_length);
+        let mut remaining_output = &mut output_vector.spare_capacity_mut()[..total_length];
+        let mut moves: Vec<(&mut Vec<T>, &mut [MaybeUninit<T>])> = vectors
+            .iter_mut()
+            .map(|v| {
+                let (destination, rest) = mem::take(&mut remaining_output).split_at_mut(v.len());
+                remaining_output = rest;
+                (v, destination)
             })
-            .unwrap_or_else(Vec::new)
+            .collect();
+        moves
+            .as_mut_slice()
+            .into_adapt_iter()
+            .with_policy(policy)
+            .for_each(|(source, destination)| unsafe {
+                // elements are moved out, the source vector just needs to forget them
+                ptr::copy_nonoverlapping(
+                    source.as_ptr(),
+                    destination.as_mut_ptr() as *mut T,
+                    source.len(),
+                );
+                source.set_len(0);
+            });
+        unsafe {
+            output_vector.set_len(total_length);
+        }
+        output_vector
     }
 }
 
-impl<T: Send + Sync> FromAdaptiveIndexedIterator<T> for Vec<T> {
-    fn from_adapt_iter<I, R, S: Iterator<Item = usize>>(runner: R) -> Self
+// Uninitialized output divided together with the indexed input filling it.
+#[derive(Divisible, DivisibleIntoBlocks)]
+#[power(IndexedPower)]
+struct IndexedOutput<'a, T: Send + Sync, I: DivisibleIntoBlocks> {
+    output: &'a mut [MaybeUninit<T>],
+    input: I,
+}
+
+impl<T: Send + Sync> FromAdaptiveIterator<T, IndexedPower> for Vec<T> {
+    fn from_adapt_iter<I, R, S>(runner: R) -> Self
     where
-        I: AdaptiveIndexedIterator<Item = T>,
-        R: AdaptiveIndexedIteratorRunner<I, S>,
+        I: AdaptiveIterator<Item = T, Power = IndexedPower>,
+        R: AdaptiveIteratorRunner<I, S>,
+        S: Iterator<Item = usize>,
     {
         let (input, policy, sizes) = runner.input_policy_sizes();
         let output_len = input.base_length();
         let mut output_vector = Vec::with_capacity(output_len);
+        // each element is written directly at its final place.
+        // we only set the length once all are written so nothing is dropped on panics.
+        let output: &mut [MaybeUninit<T>] = &mut output_vector.spare_capacity_mut()[..output_len];
+        IndexedOutput { output, input }
+            .with_policy(policy)
+            .by_blocks(sizes)
+            .partial_for_each(|pair, limit| {
+                let (todo, remaining) = pair.divide_at(limit);
+                let expected = todo.output.len();
+                let mut written = 0;
+                for (out_ref, in_ref) in todo.output.iter_mut().zip(todo.input) {
+                    out_ref.write(in_ref);
+                    written += 1;
+                }
+                assert_eq!(written, expected, "indexed input has a wrong length");
+                remaining
+            });
         unsafe {
             output_vector.set_len(output_len);
         }
-        let output_slice: &mut [T] = &mut output_vector;
-        output_slice
-            .into_adapt_iter()
-            .zip(input)
+        output_vector
+    }
+}
+
+impl<P> FromAdaptiveIterator<char, P> for String {
+    fn from_adapt_iter<I, R, S>(runner: R) -> Self
+    where
+        I: AdaptiveIterator<Item = char, Power = P>,
+        R: AdaptiveIteratorRunner<I, S>,
+        S: Iterator<Item = usize>,
+    {
+        runner
+            .fold(String::new, |mut s, c| {
+                s.push(c);
+                s
+            })
+            .reduce(|mut s1, s2| {
+                s1.push_str(&s2);
+                s1
+            })
+    }
+}
+
+impl<'a, P> FromAdaptiveIterator<&'a str, P> for String {
+    fn from_adapt_iter<I, R, S>(runner: R) -> Self
+    where
+        I: AdaptiveIterator<Item = &'a str, Power = P>,
+        R: AdaptiveIteratorRunner<I, S>,
+        S: Iterator<Item = usize>,
+    {
+        runner
+            .fold(String::new, |mut s, c| {
+                s.push_str(c);
+                s
+            })
+            .reduce(|mut s1, s2| {
+                s1.push_str(&s2);
+                s1
+            })
+    }
+}
+
+impl<P> FromAdaptiveIterator<String, P> for String {
+    fn from_adapt_iter<I, R, S>(runner: R) -> Self
+    where
+        I: AdaptiveIterator<Item = String, Power = P>,
+        R: AdaptiveIteratorRunner<I, S>,
+        S: Iterator<Item = usize>,
+    {
+        runner
+            .fold(String::new, |mut s, c| {
+                s.push_str(&c);
+                s
+            })
+            .reduce(|mut s1, s2| {
+                s1.push_str(&s2);
+                s1
+            })
+    }
+}
+
+impl<K, V, H, P> FromAdaptiveIterator<(K, V), P> for HashMap<K, V, H>
+where
+    K: Eq + Hash + Send + Sync,
+    V: Send + Sync,
+    H: BuildHasher + Default + Send + Sync,
+{
+    fn from_adapt_iter<I, R, S>(runner: R) -> Self
+    where
+        I: AdaptiveIterator<Item = (K, V), Power = P>,
+        R: AdaptiveIteratorRunner<I, S>,
+        S: Iterator<Item = usize>,
+    {
+        // last values win, like in sequential collects
+        runner
+            .fold(HashMap::default, |mut m, (k, v)| {
+                m.insert(k, v);
+                m
+            })
+            .reduce(|mut m1, m2| {
+                m1.extend(m2);
+                m1
+            })
+    }
+}
+
+impl<T, H, P> FromAdaptiveIterator<T, P> for HashSet<T, H>
+where
+    T: Eq + Hash + Send + Sync,
+    H: BuildHasher + Default + Send + Sync,
+{
+    fn from_adapt_iter<I, R, S>(runner: R) -> Self
+    where
+        I: AdaptiveIterator<Item = T, Power = P>,
+        R: AdaptiveIteratorRunner<I, S>,
+        S: Iterator<Item = usize>,
+    {
+        runner
+            .fold(HashSet::default, |mut s, e| {
+                s.insert(e);
+                s
+            })
+            .reduce(|mut s1, s2| {
+                s1.extend(s2);
+                s1
+            })
+    }
+}
+
+/// Collect all values if there is no `None` in the iterator.
+/// Like for `Result`, as soon as a `None` is found all pieces coming after it
+/// get cancelled.
+/// The values are collected like filtered ones.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::prelude::*;
+/// let halves: Option<Vec<u32>> = (0..1_000_000u32)
+///     .into_adapt_iter()
+///     .map(|i| if i == 99_999 { None } else { Some(i / 2) })
+///     .collect();
+/// assert_eq!(halves, None);
+/// let halves: Option<Vec<u32>> = (0..1_000u32).into_adapt_iter().map(Some).collect();
+/// assert_eq!(halves.map(|v| v.len()), Some(1_000));
+/// ```
+impl<T, C, P> FromAdaptiveIterator<Option<T>, P> for Option<C>
+where
+    T: Send,
+    C: FromAdaptiveIterator<T, BlockedPower>,
+{
+    fn from_adapt_iter<I, R, S>(runner: R) -> Self
+    where
+        I: AdaptiveIterator<Item = Option<T>, Power = P>,
+        R: AdaptiveIteratorRunner<I, S>,
+        S: Iterator<Item = usize>,
+    {
+        // a missing value is an error without content
+        let (input, policy, sizes) = runner.input_policy_sizes();
+        let collection: Result<C, ()> = input
+            .map(|o: Option<T>| o.ok_or(()))
             .with_policy(policy)
             .by_blocks(sizes)
-            .for_each(|(out_ref, in_ref)| unsafe { std::ptr::write(out_ref, in_ref) });
-        output_vector
+            .collect();
+        collection.ok()
+    }
+}
+
+// Input stopping at its first error, cancelling all pieces after it.
+struct UntilError<'a, I, E> {
+    input: OrderedAbortingDivisible<'a, I>,
+    // first error, with the position of its piece
+    error: &'a Mutex<Option<(usize, E)>>,
+}
+
+impl<'a, T, E, I> Divisible for UntilError<'a, I, E>
+where
+    T: Send,
+    E: Send,
+    I: DivisibleIntoBlocks + IntoIterator<Item = Result<T, E>>,
+{
+    type Power = BlockedPower;
+    fn base_length(&self) -> usize {
+        self.input.base_length()
+    }
+    fn divide(self) -> (Self, Self) {
+        let (left, right) = self.input.divide();
+        (
+            UntilError {
+                input: left,
+                error: self.error,
+            },
+            UntilError {
+                input: right,
+                error: self.error,
+            },
+        )
+    }
+}
+
+impl<'a, T, E, I> DivisibleIntoBlocks for UntilError<'a, I, E>
+where
+    T: Send,
+    E: Send,
+    I: DivisibleIntoBlocks + IntoIterator<Item = Result<T, E>>,
+{
+    fn try_divide_at(self, index: usize) -> Result<(Self, Self), DivisionError> {
+        let error = self.error;
+        self.input.try_divide_at(index).map(|(left, right)| {
+            (
+                UntilError { input: left, error },
+                UntilError {
+                    input: right,
+                    error,
+                },
+            )
+        })
+    }
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.input.divide_at(index);
+        (
+            UntilError {
+                input: left,
+                error: self.error,
+            },
+            UntilError {
+                input: right,
+                error: self.error,
+            },
+        )
+    }
+}
+
+impl<'a, T, E, I> IntoIterator for UntilError<'a, I, E>
+where
+    I: IntoIterator<Item = Result<T, E>>,
+{
+    type Item = T;
+    type IntoIter = UntilErrorIter<'a, I::IntoIter, E>;
+    fn into_iter(self) -> Self::IntoIter {
+        UntilErrorIter {
+            position: self.input.position,
+            found: self.input.found,
+            error: self.error,
+            iterator: self.input.real_content.into_iter(),
+        }
+    }
+}
+
+impl<'a, T, E, I> AdaptiveIterator for UntilError<'a, I, E>
+where
+    T: Send,
+    E: Send,
+    I: AdaptiveIterator<Item = Result<T, E>>,
+{
+}
+
+struct UntilErrorIter<'a, J, E> {
+    iterator: J,
+    position: usize,
+    found: &'a AtomicUsize,
+    error: &'a Mutex<Option<(usize, E)>>,
+}
+
+impl<'a, T, E, J: Iterator<Item = Result<T, E>>> Iterator for UntilErrorIter<'a, J, E> {
+    type Item = T;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.found.load(Ordering::Relaxed) <= self.position {
+            // we, or someone before us failed
+            return None;
+        }
+        match self.iterator.next()? {
+            Ok(value) => Some(value),
+            Err(e) => {
+                let mut error = self.error.lock().unwrap();
+                if error
+                    .as_ref()
+                    .map_or(true, |(position, _)| self.position < *position)
+                {
+                    *error = Some((self.position, e));
+                }
+                self.found.fetch_min(self.position, Ordering::Relaxed);
+                None
+            }
+        }
+    }
+}
+
+/// Collect all values if there is no error in the iterator.
+/// We return the first error and, as soon as an error is found, all
+/// pieces coming after it get cancelled.
+/// The values are collected like filtered ones.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::prelude::*;
+/// let parsed: Result<Vec<u32>, String> = (0..1_000_000u32)
+///     .into_adapt_iter()
+///     .map(|i| if i % 100_000 == 99_999 { Err(format!("bad {}", i)) } else { Ok(i) })
+///     .collect();
+/// assert_eq!(parsed, Err("bad 99999".to_string()));
+/// let parsed: Result<Vec<u32>, String> = (0..1_000u32).into_adapt_iter().map(Ok).collect();
+/// assert_eq!(parsed.map(|v| v.len()), Ok(1_000));
+/// ```
+impl<T, E, C, P> FromAdaptiveIterator<Result<T, E>, P> for Result<C, E>
+where
+    T: Send,
+    E: Send,
+    C: FromAdaptiveIterator<T, BlockedPower>,
+{
+    fn from_adapt_iter<I, R, S>(runner: R) -> Self
+    where
+        I: AdaptiveIterator<Item = Result<T, E>, Power = P>,
+        R: AdaptiveIteratorRunner<I, S>,
+        S: Iterator<Item = usize>,
+    {
+        let found = AtomicUsize::new(std::usize::MAX);
+        let error = Mutex::new(None);
+        let (input, policy, sizes) = runner.input_policy_sizes();
+        let collection = UntilError {
+            input: OrderedAbortingDivisible {
+                real_content: input,
+                position: 0,
+                found: &found,
+            },
+            error: &error,
+        }
+        .with_policy(policy)
+        .by_blocks(sizes)
+        .collect();
+        match error.into_inner().unwrap() {
+            Some((_, e)) => Err(e),
+            None => Ok(collection),
+        }
     }
 }
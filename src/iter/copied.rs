@@ -0,0 +1,32 @@
+use crate::prelude::*;
+use derive_divisible::{Divisible, DivisibleIntoBlocks};
+use std::iter;
+
+/// Adaptive iterator copying all elements.
+/// See `AdaptiveIterator::copied`.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Divisible, DivisibleIntoBlocks)]
+#[power(I::Power)]
+pub struct Copied<I: AdaptiveIterator> {
+    pub(crate) it: I,
+}
+
+impl<'a, I, T> IntoIterator for Copied<I>
+where
+    I: AdaptiveIterator<Item = &'a T>,
+    T: Copy + 'a,
+{
+    type Item = T;
+    type IntoIter = iter::Copied<I::IntoIter>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.it.into_iter().copied()
+    }
+}
+
+impl<I: AdaptiveIndexedIterator> DivisibleAtIndex for Copied<I> {}
+
+impl<'a, T: Copy + 'a, I: AdaptiveIterator<Item = &'a T>> AdaptiveIterator for Copied<I> {}
+impl<'a, T: Copy + 'a, I: AdaptiveIndexedIterator<Item = &'a T>> AdaptiveIndexedIterator
+    for Copied<I>
+{
+}
@@ -0,0 +1,42 @@
+/// Values which are either a successful output or a failure, like `Option` and `Result`.
+/// This is a stable stand-in for `std::ops::Try`, used by `try_fold` and `try_reduce`.
+pub trait Fallible: Sized {
+    /// Value obtained on success.
+    type Output;
+    /// What is left on failure (`()` for `Option`, the error for `Result`).
+    type Residual;
+    /// Convert back into a success.
+    fn from_output(output: Self::Output) -> Self;
+    /// Convert back into a failure.
+    fn from_residual(residual: Self::Residual) -> Self;
+    /// Split into success or failure.
+    fn into_result(self) -> Result<Self::Output, Self::Residual>;
+}
+
+impl<T> Fallible for Option<T> {
+    type Output = T;
+    type Residual = ();
+    fn from_output(output: T) -> Self {
+        Some(output)
+    }
+    fn from_residual(_residual: ()) -> Self {
+        None
+    }
+    fn into_result(self) -> Result<T, ()> {
+        self.ok_or(())
+    }
+}
+
+impl<T, E> Fallible for Result<T, E> {
+    type Output = T;
+    type Residual = E;
+    fn from_output(output: T) -> Self {
+        Ok(output)
+    }
+    fn from_residual(residual: E) -> Self {
+        Err(residual)
+    }
+    fn into_result(self) -> Result<T, E> {
+        self
+    }
+}
@@ -3,6 +3,8 @@ use crate::traits::BlockedPower;
 use derive_divisible::{Divisible, DivisibleIntoBlocks};
 use std::iter;
 
+/// Adaptive iterator keeping only elements satisfying a predicate.
+/// See `AdaptiveIterator::filter`.
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 #[derive(Divisible, DivisibleIntoBlocks)]
 #[power(BlockedPower)]
@@ -22,7 +24,7 @@ impl<I: AdaptiveIterator, P: Fn(&I::Item) -> bool + Clone + Send + Sync> IntoIte
     }
 }
 
-impl<I: AdaptiveIterator, P: Fn(&I::Item) -> bool + Send + Sync + Copy> AdaptiveIterator
+impl<I: AdaptiveIterator, P: Fn(&I::Item) -> bool + Send + Sync + Clone> AdaptiveIterator
     for Filter<I, P>
 {
 }
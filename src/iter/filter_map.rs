@@ -0,0 +1,30 @@
+use super::{AdaptiveIterator, Divisible, DivisibleIntoBlocks};
+use crate::traits::BlockedPower;
+use derive_divisible::{Divisible, DivisibleIntoBlocks};
+use std::iter;
+
+/// Adaptive iterator transforming elements and discarding `None` results.
+/// See `AdaptiveIterator::filter_map`.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Divisible, DivisibleIntoBlocks)]
+#[power(BlockedPower)]
+pub struct FilterMap<I: AdaptiveIterator, F: Clone + Send + Sync> {
+    pub(crate) iter: I,
+    #[divide_by(clone)]
+    pub(crate) filter_op: F,
+}
+
+impl<R: Send, I: AdaptiveIterator, F: Fn(I::Item) -> Option<R> + Clone + Send + Sync> IntoIterator
+    for FilterMap<I, F>
+{
+    type Item = R;
+    type IntoIter = iter::FilterMap<I::IntoIter, F>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter.into_iter().filter_map(self.filter_op)
+    }
+}
+
+impl<R: Send, I: AdaptiveIterator, F: Fn(I::Item) -> Option<R> + Send + Sync + Clone>
+    AdaptiveIterator for FilterMap<I, F>
+{
+}
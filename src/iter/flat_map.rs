@@ -0,0 +1,38 @@
+use super::{AdaptiveIterator, Divisible, DivisibleIntoBlocks};
+use crate::traits::BlockedPower;
+use derive_divisible::{Divisible, DivisibleIntoBlocks};
+use std::iter;
+
+/// Adaptive iterator expanding each element into a sequential iterator.
+/// See `AdaptiveIterator::flat_map`.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Divisible, DivisibleIntoBlocks)]
+#[power(BlockedPower)]
+pub struct FlatMap<I: AdaptiveIterator, F: Clone + Send + Sync> {
+    pub(crate) iter: I,
+    #[divide_by(clone)]
+    pub(crate) map_op: F,
+}
+
+impl<U, I, F> IntoIterator for FlatMap<I, F>
+where
+    U: IntoIterator,
+    U::Item: Send,
+    I: AdaptiveIterator,
+    F: Fn(I::Item) -> U + Clone + Send + Sync,
+{
+    type Item = U::Item;
+    type IntoIter = iter::FlatMap<I::IntoIter, U, F>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter.into_iter().flat_map(self.map_op)
+    }
+}
+
+impl<U, I, F> AdaptiveIterator for FlatMap<I, F>
+where
+    U: IntoIterator,
+    U::Item: Send,
+    I: AdaptiveIterator,
+    F: Fn(I::Item) -> U + Clone + Send + Sync,
+{
+}
@@ -0,0 +1,120 @@
+use super::AdaptiveIterator;
+use crate::traits::{BlockedPower, Divisible, DivisibleIntoBlocks};
+use std::iter;
+use std::option;
+
+/// Adaptive iterator over all elements of divisible items.
+/// See `AdaptiveIterator::flatten`.
+///
+/// We divide across outer items first. Once a piece is down to one outer
+/// item we open it up and divide inside it, so one large item
+/// among small ones still gets shared between workers.
+/// Lengths count inner elements for the opened item and one for each outer item.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct Flatten<I: AdaptiveIterator>
+where
+    I::Item: DivisibleIntoBlocks,
+{
+    inner: Option<I::Item>,
+    outer: I,
+}
+
+impl<I: AdaptiveIterator> Flatten<I>
+where
+    I::Item: DivisibleIntoBlocks,
+{
+    pub(crate) fn new(outer: I) -> Self {
+        Flatten { inner: None, outer }.opened()
+    }
+    fn inner_length(&self) -> usize {
+        self.inner.as_ref().map(|i| i.base_length()).unwrap_or(0)
+    }
+    // open up the last outer item if it is all we have
+    fn opened(self) -> Self {
+        if self.inner_length() == 0 && self.outer.base_length() == 1 {
+            let (empty, last) = self.outer.divide_at(0);
+            Flatten {
+                inner: last.into_iter().next(),
+                outer: empty,
+            }
+        } else {
+            self
+        }
+    }
+}
+
+impl<I: AdaptiveIterator> Divisible for Flatten<I>
+where
+    I::Item: DivisibleIntoBlocks,
+{
+    type Power = BlockedPower;
+    fn base_length(&self) -> usize {
+        self.inner_length() + self.outer.base_length()
+    }
+    fn divide(self) -> (Self, Self) {
+        let mid = self.base_length() / 2;
+        self.divide_at(mid)
+    }
+}
+
+impl<I: AdaptiveIterator> DivisibleIntoBlocks for Flatten<I>
+where
+    I::Item: DivisibleIntoBlocks,
+{
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        let inner_length = self.inner_length();
+        if index <= inner_length {
+            let (left_inner, right_inner) = match self.inner {
+                Some(inner) => {
+                    let (left, right) = inner.divide_at(index);
+                    (Some(left), Some(right))
+                }
+                None => (None, None),
+            };
+            let (empty, outer) = self.outer.divide_at(0);
+            (
+                Flatten {
+                    inner: left_inner,
+                    outer: empty,
+                },
+                Flatten {
+                    inner: right_inner,
+                    outer,
+                }
+                .opened(),
+            )
+        } else {
+            let (left_outer, right_outer) = self.outer.divide_at(index - inner_length);
+            (
+                Flatten {
+                    inner: self.inner,
+                    outer: left_outer,
+                }
+                .opened(),
+                Flatten {
+                    inner: None,
+                    outer: right_outer,
+                }
+                .opened(),
+            )
+        }
+    }
+}
+
+impl<I: AdaptiveIterator> IntoIterator for Flatten<I>
+where
+    I::Item: IntoIterator + DivisibleIntoBlocks,
+{
+    type Item = <I::Item as IntoIterator>::Item;
+    type IntoIter = iter::Flatten<iter::Chain<option::IntoIter<I::Item>, I::IntoIter>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.inner.into_iter().chain(self.outer).flatten()
+    }
+}
+
+impl<I: AdaptiveIterator> AdaptiveIterator for Flatten<I>
+where
+    I::Item: IntoIterator + DivisibleIntoBlocks,
+    <I::Item as IntoIterator>::Item: Send,
+{
+}
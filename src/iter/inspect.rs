@@ -0,0 +1,33 @@
+use crate::prelude::*;
+use derive_divisible::{Divisible, DivisibleIntoBlocks};
+use std::iter;
+
+/// Adaptive iterator calling a closure on each element before passing it on.
+/// See `AdaptiveIterator::inspect`.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Divisible, DivisibleIntoBlocks)]
+#[power(I::Power)]
+pub struct Inspect<I: AdaptiveIterator, F: Clone + Send + Sync> {
+    pub(crate) base: I,
+    #[divide_by(clone)]
+    pub(crate) inspect_op: F,
+}
+
+impl<I: AdaptiveIterator, F: Fn(&I::Item) + Clone + Send + Sync> IntoIterator for Inspect<I, F> {
+    type Item = I::Item;
+    type IntoIter = iter::Inspect<I::IntoIter, F>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.base.into_iter().inspect(self.inspect_op)
+    }
+}
+
+impl<I: AdaptiveIndexedIterator, F: Send + Sync + Clone> DivisibleAtIndex for Inspect<I, F> {}
+
+impl<I: AdaptiveIterator, F: Fn(&I::Item) + Send + Sync + Clone> AdaptiveIterator
+    for Inspect<I, F>
+{
+}
+impl<I: AdaptiveIndexedIterator, F: Fn(&I::Item) + Send + Sync + Clone> AdaptiveIndexedIterator
+    for Inspect<I, F>
+{
+}
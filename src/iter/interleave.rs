@@ -0,0 +1,112 @@
+use crate::prelude::*;
+use crate::traits::IndexedPower;
+use std::cmp::{max, min};
+
+/// Adaptive iterator alternating elements of two indexed iterators.
+/// Once the shortest one is exhausted the remaining elements of the other follow.
+/// See `AdaptiveIndexedIterator::interleave`.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct Interleave<A, B> {
+    pub(crate) a: A,
+    pub(crate) b: B,
+    // true if next element comes from b
+    pub(crate) b_first: bool,
+}
+
+impl<A: DivisibleAtIndex, B: DivisibleAtIndex> Divisible for Interleave<A, B> {
+    type Power = IndexedPower;
+    fn base_length(&self) -> usize {
+        self.a.base_length() + self.b.base_length()
+    }
+    fn divide(self) -> (Self, Self) {
+        let mid = self.base_length() / 2;
+        self.divide_at(mid)
+    }
+}
+
+impl<A: DivisibleAtIndex, B: DivisibleAtIndex> DivisibleIntoBlocks for Interleave<A, B> {
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        let (a_length, b_length) = (self.a.base_length(), self.b.base_length());
+        let (first_length, second_length) = if self.b_first {
+            (b_length, a_length)
+        } else {
+            (a_length, b_length)
+        };
+        // how many elements of the side coming first end up on the left
+        let first_taken = min(
+            first_length,
+            max(index.div_ceil(2), index.saturating_sub(second_length)),
+        );
+        let second_taken = index - first_taken;
+        let (a_taken, b_taken) = if self.b_first {
+            (second_taken, first_taken)
+        } else {
+            (first_taken, second_taken)
+        };
+        let (left_a, right_a) = self.a.divide_at(a_taken);
+        let (left_b, right_b) = self.b.divide_at(b_taken);
+        (
+            Interleave {
+                a: left_a,
+                b: left_b,
+                b_first: self.b_first,
+            },
+            Interleave {
+                a: right_a,
+                b: right_b,
+                b_first: self.b_first ^ (first_taken != second_taken),
+            },
+        )
+    }
+}
+
+impl<A: DivisibleAtIndex, B: DivisibleAtIndex> DivisibleAtIndex for Interleave<A, B> {}
+
+/// Sequential iterator for `Interleave` blocks.
+pub struct InterleaveIter<I, J> {
+    a: I,
+    b: J,
+    b_first: bool,
+}
+
+impl<I: Iterator, J: Iterator<Item = I::Item>> Iterator for InterleaveIter<I, J> {
+    type Item = I::Item;
+    fn next(&mut self) -> Option<I::Item> {
+        let next = if self.b_first {
+            self.b.next().or_else(|| self.a.next())
+        } else {
+            self.a.next().or_else(|| self.b.next())
+        };
+        self.b_first = !self.b_first;
+        next
+    }
+}
+
+impl<A, B> IntoIterator for Interleave<A, B>
+where
+    A: DivisibleAtIndex + IntoIterator,
+    B: DivisibleAtIndex + IntoIterator<Item = A::Item>,
+{
+    type Item = A::Item;
+    type IntoIter = InterleaveIter<A::IntoIter, B::IntoIter>;
+    fn into_iter(self) -> Self::IntoIter {
+        InterleaveIter {
+            a: self.a.into_iter(),
+            b: self.b.into_iter(),
+            b_first: self.b_first,
+        }
+    }
+}
+
+impl<A, B> AdaptiveIterator for Interleave<A, B>
+where
+    A: AdaptiveIndexedIterator,
+    B: AdaptiveIndexedIterator<Item = A::Item>,
+{
+}
+impl<A, B> AdaptiveIndexedIterator for Interleave<A, B>
+where
+    A: AdaptiveIndexedIterator,
+    B: AdaptiveIndexedIterator<Item = A::Item>,
+{
+}
@@ -2,6 +2,8 @@ use crate::prelude::*;
 use derive_divisible::{Divisible, DivisibleIntoBlocks};
 use std::iter;
 
+/// Adaptive iterator applying a closure on each element.
+/// See `AdaptiveIterator::map`.
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 #[derive(Divisible, DivisibleIntoBlocks)]
 #[power(I::Power)]
@@ -23,11 +25,11 @@ impl<R: Send, I: AdaptiveIterator, F: Fn(I::Item) -> R + Clone + Send + Sync> In
 
 impl<I: AdaptiveIndexedIterator, F: Send + Sync + Clone> DivisibleAtIndex for Map<I, F> {}
 
-impl<R: Send, I: AdaptiveIterator, F: Fn(I::Item) -> R + Send + Sync + Copy> AdaptiveIterator
+impl<R: Send, I: AdaptiveIterator, F: Fn(I::Item) -> R + Send + Sync + Clone> AdaptiveIterator
     for Map<I, F>
 {
 }
-impl<R: Send, I: AdaptiveIndexedIterator, F: Fn(I::Item) -> R + Send + Sync + Copy>
+impl<R: Send, I: AdaptiveIndexedIterator, F: Fn(I::Item) -> R + Send + Sync + Clone>
     AdaptiveIndexedIterator for Map<I, F>
 {
 }
@@ -0,0 +1,127 @@
+use crate::prelude::*;
+use derive_divisible::{Divisible, DivisibleIntoBlocks};
+
+/// Adaptive iterator applying a closure on each element with some state
+/// created by `init_op` once per sequential block.
+/// See `AdaptiveIterator::map_init`.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Divisible, DivisibleIntoBlocks)]
+#[power(I::Power)]
+pub struct MapInit<I: AdaptiveIterator, INIT: Clone + Send + Sync, F: Clone + Send + Sync> {
+    pub(crate) base: I,
+    #[divide_by(clone)]
+    pub(crate) init_op: INIT,
+    #[divide_by(clone)]
+    pub(crate) map_op: F,
+}
+
+/// Adaptive iterator applying a closure on each element with a clone of
+/// some state for each sequential block.
+/// See `AdaptiveIterator::map_with`.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Divisible, DivisibleIntoBlocks)]
+#[power(I::Power)]
+pub struct MapWith<I: AdaptiveIterator, T: Clone + Send + Sync, F: Clone + Send + Sync> {
+    pub(crate) base: I,
+    #[divide_by(clone)]
+    pub(crate) state: T,
+    #[divide_by(clone)]
+    pub(crate) map_op: F,
+}
+
+/// Sequential iterator for `MapInit` and `MapWith` blocks.
+pub struct MapStateIter<J, T, F> {
+    iter: J,
+    state: T,
+    map_op: F,
+}
+
+impl<R, J: Iterator, T, F: Fn(&mut T, J::Item) -> R> Iterator for MapStateIter<J, T, F> {
+    type Item = R;
+    fn next(&mut self) -> Option<R> {
+        let state = &mut self.state;
+        let map_op = &self.map_op;
+        self.iter.next().map(|e| map_op(state, e))
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<R, T, I, INIT, F> IntoIterator for MapInit<I, INIT, F>
+where
+    R: Send,
+    I: AdaptiveIterator,
+    INIT: Fn() -> T + Clone + Send + Sync,
+    F: Fn(&mut T, I::Item) -> R + Clone + Send + Sync,
+{
+    type Item = R;
+    type IntoIter = MapStateIter<I::IntoIter, T, F>;
+    fn into_iter(self) -> Self::IntoIter {
+        MapStateIter {
+            iter: self.base.into_iter(),
+            state: (self.init_op)(),
+            map_op: self.map_op,
+        }
+    }
+}
+
+impl<R, I, T, F> IntoIterator for MapWith<I, T, F>
+where
+    R: Send,
+    I: AdaptiveIterator,
+    T: Clone + Send + Sync,
+    F: Fn(&mut T, I::Item) -> R + Clone + Send + Sync,
+{
+    type Item = R;
+    type IntoIter = MapStateIter<I::IntoIter, T, F>;
+    fn into_iter(self) -> Self::IntoIter {
+        MapStateIter {
+            iter: self.base.into_iter(),
+            state: self.state,
+            map_op: self.map_op,
+        }
+    }
+}
+
+impl<I: AdaptiveIndexedIterator, INIT: Send + Sync + Clone, F: Send + Sync + Clone> DivisibleAtIndex
+    for MapInit<I, INIT, F>
+{
+}
+impl<I: AdaptiveIndexedIterator, T: Send + Sync + Clone, F: Send + Sync + Clone> DivisibleAtIndex
+    for MapWith<I, T, F>
+{
+}
+
+impl<R, T, I, INIT, F> AdaptiveIterator for MapInit<I, INIT, F>
+where
+    R: Send,
+    I: AdaptiveIterator,
+    INIT: Fn() -> T + Clone + Send + Sync,
+    F: Fn(&mut T, I::Item) -> R + Clone + Send + Sync,
+{
+}
+impl<R, T, I, INIT, F> AdaptiveIndexedIterator for MapInit<I, INIT, F>
+where
+    R: Send,
+    I: AdaptiveIndexedIterator,
+    INIT: Fn() -> T + Clone + Send + Sync,
+    F: Fn(&mut T, I::Item) -> R + Clone + Send + Sync,
+{
+}
+impl<R, I, T, F> AdaptiveIterator for MapWith<I, T, F>
+where
+    R: Send,
+    I: AdaptiveIterator,
+    T: Clone + Send + Sync,
+    F: Fn(&mut T, I::Item) -> R + Clone + Send + Sync,
+{
+}
+impl<R, I, T, F> AdaptiveIndexedIterator for MapWith<I, T, F>
+where
+    R: Send,
+    I: AdaptiveIndexedIterator,
+    T: Clone + Send + Sync,
+    F: Fn(&mut T, I::Item) -> R + Clone + Send + Sync,
+{
+}
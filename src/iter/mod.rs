@@ -1,7 +1,8 @@
 use crate::activated_input::ActivatedInput;
-use crate::folders::{fold::Fold, iterator_fold::AdaptiveIteratorFold};
+use crate::folders::fold::Fold;
+use crate::folders::iterator_fold::{AdaptiveIteratorFold, AdaptiveIteratorFoldWith};
 use crate::prelude::*;
-use crate::traits::{BlockedOrMore, BlockedPower};
+use crate::traits::BlockedOrMore;
 use std::iter::Empty;
 use std::marker::PhantomData;
 pub mod map;
@@ -12,18 +13,40 @@ pub mod zip;
 use self::zip::Zip;
 mod cloned;
 use self::cloned::Cloned;
+mod copied;
+use self::copied::Copied;
 mod filter;
 use self::filter::Filter;
+mod filter_map;
+use self::filter_map::FilterMap;
+mod flat_map;
+use self::flat_map::FlatMap;
+mod flatten;
+use self::flatten::Flatten;
+mod inspect;
+use self::inspect::Inspect;
+mod interleave;
+use self::interleave::Interleave;
+mod update;
+use self::update::Update;
+mod map_init;
+use self::map_init::{MapInit, MapWith};
+use crate::algorithms::prefix::adaptive_prefix;
+use crate::concat::Chain;
 use crate::policy::ParametrizedInput;
+use itertools::Either;
 use std;
-use std::cmp::min;
+use std::cmp::{max, min, Ordering};
+use std::collections::{LinkedList, VecDeque};
 mod collect;
-pub use self::collect::{FromAdaptiveBlockedIterator, FromAdaptiveIndexedIterator};
+pub use self::collect::FromAdaptiveIterator;
+mod fallible;
+pub use self::fallible::Fallible;
 pub(crate) mod hash;
 pub(crate) mod str;
 use crate::utils::powers;
-use crate::utils::AbortingDivisible;
-use std::sync::atomic::{AtomicBool, Ordering};
+use crate::utils::{AbortingDivisible, Enumerate, OrderedAbortingDivisible, Rev, StepBy};
+use std::sync::atomic::{self, AtomicBool, AtomicUsize};
 
 pub trait IntoAdaptiveIterator: IntoIterator + DivisibleIntoBlocks {
     fn into_adapt_iter(self) -> Iter<Self> {
@@ -42,8 +65,40 @@ pub trait AdaptiveIterator: IntoIterator + DivisibleIntoBlocks {
     {
         Cloned { it: self }
     }
-    //TODO: functions implement Copy but not clone ?????
-    //what about Sync and Send ???
+    /// Creates an iterator which copies all of its elements.
+    /// This is useful when you have an iterator over &T, but you need an iterator over T.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let v: Vec<u64> = (0..10_000).collect();
+    /// let m = v.as_slice().into_adapt_iter().copied().reduce(|| 0, std::cmp::max);
+    /// assert_eq!(m, 9_999);
+    /// ```
+    fn copied<'a, T: 'a + Copy>(self) -> Copied<Self>
+    where
+        Self: AdaptiveIterator<Item = &'a T>,
+    {
+        Copied { it: self }
+    }
+    /// Keep only elements satisfying given predicate.
+    /// Filtered iterators can only be divided into blocks (their final length is unknown)
+    /// so collecting them fills one vector per task, which are then compacted in parallel.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let v: Vec<u32> = (0..100_000).collect();
+    /// let multiples: Vec<u32> = v
+    ///     .as_slice()
+    ///     .into_adapt_iter()
+    ///     .filter(|&&e| e % 3 == 0)
+    ///     .cloned()
+    ///     .collect();
+    /// assert_eq!(multiples, (0..100_000).step_by(3).collect::<Vec<u32>>());
+    /// ```
     fn filter<P: Fn(&Self::Item) -> bool + Clone + Sync + Send>(
         self,
         predicate: P,
@@ -53,14 +108,246 @@ pub trait AdaptiveIterator: IntoIterator + DivisibleIntoBlocks {
             predicate,
         }
     }
-    fn map<R: Send, F: Fn(Self::Item) -> R + Send + Sync + Copy>(self, map_op: F) -> Map<Self, F> {
+    /// Apply `filter_op` on each element, keeping only the `Some` results.
+    /// This fuses `map` and `filter` in one pass and collects like `filter`.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let records: Vec<String> = (0..10_000)
+    ///     .map(|i| if i % 10 == 0 { "invalid".to_string() } else { i.to_string() })
+    ///     .collect();
+    /// let values: Vec<u32> = records
+    ///     .as_slice()
+    ///     .into_adapt_iter()
+    ///     .filter_map(|r| r.parse::<u32>().ok())
+    ///     .collect();
+    /// assert_eq!(values.len(), 9_000);
+    /// assert_eq!(&values[..3], &[1, 2, 3]);
+    /// ```
+    fn filter_map<R: Send, F: Fn(Self::Item) -> Option<R> + Clone + Sync + Send>(
+        self,
+        filter_op: F,
+    ) -> FilterMap<Self, F> {
+        FilterMap {
+            iter: self,
+            filter_op,
+        }
+    }
+    /// Expand each element into a sequential iterator and yield all their elements.
+    /// Only the outer elements get divided, each expansion being iterated sequentially
+    /// inside its block.
+    /// Like `filter`, collecting fills one vector per task.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let lines: Vec<String> = (0..1_000).map(|i| format!("{} {}", i, i + 1)).collect();
+    /// let words: Vec<&str> = lines
+    ///     .as_slice()
+    ///     .into_adapt_iter()
+    ///     .flat_map(|l| l.split_whitespace())
+    ///     .collect();
+    /// assert_eq!(words.len(), 2_000);
+    /// assert_eq!(&words[..4], &["0", "1", "1", "2"]);
+    /// let s: u32 = (0..100u32).into_adapt_iter().flat_map(|i| 0..i).sum();
+    /// assert_eq!(s, (0..100u32).map(|i| i * i.saturating_sub(1) / 2).sum());
+    /// ```
+    fn flat_map<U, F>(self, map_op: F) -> FlatMap<Self, F>
+    where
+        U: IntoIterator,
+        U::Item: Send,
+        F: Fn(Self::Item) -> U + Clone + Sync + Send,
+    {
+        FlatMap { iter: self, map_op }
+    }
+    /// Iterate on our elements and then on the elements of `other`.
+    /// Both iterators need the same power; divisions happen at the junction
+    /// or inside one of them.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let v: Vec<u32> = (0..1_000).collect();
+    /// let all: Vec<u32> = v
+    ///     .as_slice()
+    ///     .into_adapt_iter()
+    ///     .cloned()
+    ///     .chain((1_000..2_000).into_adapt_iter())
+    ///     .collect();
+    /// assert_eq!(all, (0..2_000).collect::<Vec<u32>>());
+    /// ```
+    fn chain<C>(self, other: C) -> Chain<Self, C>
+    where
+        C: AdaptiveIterator<Item = Self::Item, Power = Self::Power>,
+    {
+        Chain::new(self, other)
+    }
+    /// Iterate on all elements of our divisible items.
+    /// Pieces get divided across items first and then inside large items,
+    /// so jagged data still balances well.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let mut jagged: Vec<Vec<u64>> = (0..10).map(|i| (0..i).collect()).collect();
+    /// jagged.push((0..100_000).collect());
+    /// let s: u64 = jagged
+    ///     .as_slice()
+    ///     .into_adapt_iter()
+    ///     .map(|v| v.as_slice())
+    ///     .flatten()
+    ///     .sum();
+    /// assert_eq!(s, 120 + 99_999 * 50_000);
+    /// let v: Vec<u32> = vec![0..3u32, 3..3, 3..10]
+    ///     .into_adapt_iter()
+    ///     .cloned()
+    ///     .flatten()
+    ///     .collect();
+    /// assert_eq!(v, (0..10).collect::<Vec<u32>>());
+    /// ```
+    fn flatten(self) -> Flatten<Self>
+    where
+        Self::Item: IntoIterator + DivisibleIntoBlocks,
+        <Self::Item as IntoIterator>::Item: Send,
+    {
+        Flatten::new(self)
+    }
+    /// Call `inspect_op` on a reference to each element before passing it on.
+    /// Useful to look at what flows through a pipeline.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// let seen = AtomicUsize::new(0);
+    /// let s: u32 = (0..1_000u32)
+    ///     .into_adapt_iter()
+    ///     .inspect(|_| {
+    ///         seen.fetch_add(1, Ordering::Relaxed);
+    ///     })
+    ///     .filter(|&x| x % 2 == 0)
+    ///     .sum();
+    /// assert_eq!(s, 998 * 250);
+    /// assert_eq!(seen.load(Ordering::Relaxed), 1_000);
+    /// ```
+    fn inspect<F: Fn(&Self::Item) + Clone + Sync + Send>(self, inspect_op: F) -> Inspect<Self, F> {
+        Inspect {
+            base: self,
+            inspect_op,
+        }
+    }
+    /// Mutate each element with `update_op` before passing it on.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let mut v: Vec<u32> = (0..1_000).collect();
+    /// v.as_mut_slice()
+    ///     .into_adapt_iter()
+    ///     .update(|x| **x *= 2)
+    ///     .for_each(drop);
+    /// assert_eq!(v[999], 1_998);
+    /// let odds: Vec<u32> = (0..10).into_adapt_iter().update(|x| *x = *x * 2 + 1).collect();
+    /// assert_eq!(odds, vec![1, 3, 5, 7, 9, 11, 13, 15, 17, 19]);
+    /// ```
+    fn update<F: Fn(&mut Self::Item) + Clone + Sync + Send>(self, update_op: F) -> Update<Self, F> {
+        Update {
+            base: self,
+            update_op,
+        }
+    }
+    /// Apply `map_op` on each element.
+    /// The closure gets cloned on each division, so it may own some data.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let offsets: Vec<u64> = vec![1, 2, 3];
+    /// let s: u64 = (0..1_000u64)
+    ///     .into_adapt_iter()
+    ///     .map(move |x| x * 2 + offsets[(x % 3) as usize])
+    ///     .sum();
+    /// assert_eq!(s, 999 * 1_000 + 334 + 333 * 2 + 333 * 3);
+    /// let v: Vec<_> = (0..10).into_adapt_iter().map(|x| x * x).collect();
+    /// assert_eq!(v, vec![0, 1, 4, 9, 16, 25, 36, 49, 64, 81]);
+    /// ```
+    fn map<R: Send, F: Fn(Self::Item) -> R + Send + Sync + Clone>(self, map_op: F) -> Map<Self, F> {
         Map { base: self, map_op }
     }
+    /// Apply `map_op` on each element, with mutable access to a value created
+    /// by `init_op` once per sequential block (and not once per element).
+    /// Use it for scratch buffers, random number generators, ...
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let words: Vec<String> = (0..1_000).map(|i| i.to_string()).collect();
+    /// let reversed: Vec<String> = words
+    ///     .as_slice()
+    ///     .into_adapt_iter()
+    ///     .map_init(String::new, |buffer, w| {
+    ///         buffer.clear();
+    ///         buffer.extend(w.chars().rev());
+    ///         buffer.clone()
+    ///     })
+    ///     .collect();
+    /// assert_eq!(reversed[123], "321");
+    /// ```
+    fn map_init<T, R, INIT, F>(self, init_op: INIT, map_op: F) -> MapInit<Self, INIT, F>
+    where
+        R: Send,
+        INIT: Fn() -> T + Clone + Send + Sync,
+        F: Fn(&mut T, Self::Item) -> R + Clone + Send + Sync,
+    {
+        MapInit {
+            base: self,
+            init_op,
+            map_op,
+        }
+    }
+    /// Apply `map_op` on each element, with mutable access to a clone of `init`
+    /// for each sequential block.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let v: Vec<usize> = (0..1_000)
+    ///     .into_adapt_iter()
+    ///     .map_with(vec![0; 3], |counts, x| {
+    ///         counts[x % 3] += 1;
+    ///         x * 2
+    ///     })
+    ///     .collect();
+    /// assert_eq!(v[999], 1_998);
+    /// ```
+    fn map_with<T, R, F>(self, init: T, map_op: F) -> MapWith<Self, T, F>
+    where
+        R: Send,
+        T: Clone + Send + Sync,
+        F: Fn(&mut T, Self::Item) -> R + Clone + Send + Sync,
+    {
+        MapWith {
+            base: self,
+            state: init,
+            map_op,
+        }
+    }
 }
 
 /// These iterators allow zipping, skipping and taking.
 pub trait AdaptiveIndexedIterator: AdaptiveIterator + DivisibleAtIndex {
     /// Zip the two given iterators together.
+    /// Both get divided in lockstep and the longest one is truncated.
     ///
     /// Example:
     ///
@@ -71,15 +358,156 @@ pub trait AdaptiveIndexedIterator: AdaptiveIterator + DivisibleAtIndex {
     /// // let's compute the scalar product
     /// let s:u32 = v1.into_adapt_iter().zip(v2.into_adapt_iter()).map(|(x1, x2)| x1*x2).sum();
     /// assert_eq!(s, 2000);
+    /// let pairs = (0..10).into_adapt_iter().zip((0..1_000).into_adapt_iter());
+    /// assert_eq!(pairs.base_length(), 10);
     /// ```
     fn zip<U: AdaptiveIndexedIterator>(self, other: U) -> Zip<Self, U> {
-        Zip { a: self, b: other }
+        let length = min(self.base_length(), other.base_length());
+        Zip {
+            a: self.divide_at(length).0,
+            b: other.divide_at(length).0,
+        }
+    }
+    /// Yield `(index, element)` pairs, with indices counted from the start of the whole
+    /// iterator whatever the divisions.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let v: Vec<u32> = (0..10_000).map(|i| (i * 7_919) % 10_007).collect();
+    /// let (position, max) = v
+    ///     .as_slice()
+    ///     .into_adapt_iter()
+    ///     .enumerate()
+    ///     .map(|(i, &e)| (e, i))
+    ///     .max()
+    ///     .map(|(e, i)| (i, e))
+    ///     .unwrap();
+    /// assert_eq!(v[position], max);
+    /// assert_eq!(max, *v.iter().max().unwrap());
+    /// ```
+    fn enumerate(self) -> Enumerate<Self> {
+        self.enumerate_divisible()
+    }
+    /// Keep only the first `n` elements (or all if we have less than `n`).
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let s: u64 = (0..1_000_000u64).into_adapt_iter().take(1_000).sum();
+    /// assert_eq!(s, 999 * 500);
+    /// assert_eq!((0..10).into_adapt_iter().take(20).base_length(), 10);
+    /// ```
+    fn take(self, n: usize) -> Self {
+        let length = min(n, self.base_length());
+        self.divide_at(length).0
+    }
+    /// Skip the first `n` elements (all of them if we have less than `n`).
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let v: Vec<u32> = (0..1_000).into_adapt_iter().skip(990).collect();
+    /// assert_eq!(v, (990..1_000).collect::<Vec<u32>>());
+    /// assert_eq!((0..10).into_adapt_iter().skip(20).base_length(), 0);
+    /// ```
+    fn skip(self, n: usize) -> Self {
+        let length = min(n, self.base_length());
+        self.divide_at(length).1
+    }
+    /// Iterate in reverse order.
+    /// Divisions get flipped too, so the left part of any division holds
+    /// the last elements.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let v: Vec<u32> = (0..1_000).into_adapt_iter().rev().collect();
+    /// assert_eq!(v, (0..1_000).rev().collect::<Vec<u32>>());
+    /// let last_even = (0..1_001u32).into_adapt_iter().rev().find_first(|&x| x % 2 == 0);
+    /// assert_eq!(last_even, Some(1_000));
+    /// ```
+    fn rev(self) -> Rev<Self>
+    where
+        Self::IntoIter: DoubleEndedIterator,
+    {
+        self.rev_divisible()
+    }
+    /// Keep one element every `step` elements, starting with the first one.
+    /// Divisions happen on the strided indices so pieces stay balanced.
+    /// Panics if `step` is 0.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let v: Vec<u32> = (0..1_000).into_adapt_iter().step_by(7).collect();
+    /// assert_eq!(v, (0..1_000).step_by(7).collect::<Vec<u32>>());
+    /// assert_eq!((0..10).into_adapt_iter().step_by(3).base_length(), 4);
+    /// ```
+    fn step_by(self, step: usize) -> StepBy<Self> {
+        self.step_by_divisible(step)
+    }
+    /// Alternate our elements with the elements of `other`, starting with ours.
+    /// Once one side is exhausted the remaining elements of the other one follow.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let v: Vec<u32> = (0..1_000)
+    ///     .into_adapt_iter()
+    ///     .map(|x| 2 * x)
+    ///     .interleave((0..1_500).into_adapt_iter().map(|x| 2 * x + 1))
+    ///     .collect();
+    /// let expected: Vec<u32> = (0..2_000).chain((1_000..1_500).map(|x| 2 * x + 1)).collect();
+    /// assert_eq!(v, expected);
+    /// ```
+    fn interleave<U>(self, other: U) -> Interleave<Self, U>
+    where
+        U: AdaptiveIndexedIterator<Item = Self::Item>,
+    {
+        Interleave {
+            a: self,
+            b: other,
+            b_first: false,
+        }
+    }
+    /// Zip the two given iterators together, panicking if their lengths differ.
+    ///
+    /// Example:
+    ///
+    /// ```should_panic
+    /// use rayon_adaptive::prelude::*;
+    /// let v1 = vec![1u32; 1000];
+    /// let v2 = vec![2u32; 999];
+    /// let _zipped = v1.into_adapt_iter().zip_eq(v2.into_adapt_iter());
+    /// ```
+    fn zip_eq<U: AdaptiveIndexedIterator>(self, other: U) -> Zip<Self, U> {
+        Zip::new(self, other)
     }
 }
 
 pub trait AdaptiveIteratorRunner<I: AdaptiveIterator, S: Iterator<Item = usize>>:
     AdaptiveRunner<I, S>
 {
+    /// Find any e in iterator such that predicate(e) is true.
+    /// All workers stop as soon as something is found, even in the middle of
+    /// their sequential blocks.
+    /// This is cheaper than `find_first` when we do not care which element we get.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let found = (0..1_000_000u64).into_adapt_iter().find_any(|&x| x % 100_000 == 99_999);
+    /// assert_eq!(found.map(|x| x % 100_000), Some(99_999));
+    /// assert_eq!((0..1_000u64).into_adapt_iter().find_any(|&x| x > 1_000), None);
+    /// ```
     fn find_any<P>(self, predicate: P) -> Option<I::Item>
     where
         P: Fn(&I::Item) -> bool + Sync + Send,
@@ -100,9 +528,12 @@ pub trait AdaptiveIteratorRunner<I: AdaptiveIterator, S: Iterator<Item = usize>>
                     if f.is_some() {
                         f
                     } else {
-                        let new_f = i.into_iter().find(&predicate);
+                        let new_f = i
+                            .into_iter()
+                            .take_while(|_| !found.load(atomic::Ordering::Relaxed))
+                            .find(&predicate);
                         if new_f.is_some() {
-                            found.store(true, Ordering::Relaxed)
+                            found.store(true, atomic::Ordering::Relaxed)
                         }
                         new_f
                     }
@@ -114,40 +545,90 @@ pub trait AdaptiveIteratorRunner<I: AdaptiveIterator, S: Iterator<Item = usize>>
     }
 
     /// Find first e in iterator such that predicate(e) is true.
-    /// This implementation is efficient.
+    /// This implementation is efficient: once something is found all
+    /// pieces coming after it are cancelled while pieces before it go on searching.
     ///
     /// Example:
     ///
     /// ```
     /// use rayon_adaptive::prelude::*;
     /// assert_eq!((0..1000).into_adapt_iter().find_first(|&x| x == 100), Some(100));
+    /// let v: Vec<u32> = (0..100_000).map(|i| i % 5_000).collect();
+    /// let found = v
+    ///     .as_slice()
+    ///     .into_adapt_iter()
+    ///     .enumerate()
+    ///     .find_first(|&(_, &e)| e == 4_321);
+    /// assert_eq!(found, Some((4_321, &4_321)));
+    /// assert_eq!((7..8).into_adapt_iter().find_first(|&x| x == 7), Some(7));
     /// ```
     fn find_first<P>(self, predicate: P) -> Option<I::Item>
     where
         P: Fn(&I::Item) -> bool + Sync + Send,
         I::Item: Sync + Send,
     {
+        search_first(self, |piece: I, _| piece.into_iter().find(&predicate))
+    }
+    /// Collect all elements before the first one failing the predicate.
+    /// Macro blocks are processed in order and no more blocks get scheduled once
+    /// the predicate failed. Inside a block all pieces coming after the failure
+    /// are cancelled.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let prefix = (0..u64::MAX).into_adapt_iter().take_while(|&x| x < 10_000);
+    /// assert_eq!(prefix, (0..10_000).collect::<Vec<u64>>());
+    /// let v: Vec<u32> = (0..1_000).collect();
+    /// let all = v.as_slice().into_adapt_iter().take_while(|_| true);
+    /// assert_eq!(all.len(), 1_000);
+    /// assert_eq!((7..8).into_adapt_iter().take_while(|_| true), vec![7]);
+    /// ```
+    fn take_while<P>(self, predicate: P) -> Vec<I::Item>
+    where
+        P: Fn(&I::Item) -> bool + Sync + Send,
+        I::Item: Send + Sync,
+    {
+        let failed_position = AtomicUsize::new(std::usize::MAX);
         let (input, policy, sizes) = self.input_policy_sizes();
         let len = input.base_length();
-        let base_size = min((len as f64).log(2.0).ceil() as usize, len);
-        input
-            .with_policy(policy)
-            .by_blocks(sizes.chain(powers(base_size)))
-            .partial_fold(
-                || None,
-                |found, i, limit| {
-                    //TODO: nothing is remaining if found.
-                    //should we have options ???
-                    let (todo, remaining) = i.divide_at(limit);
-                    (
-                        found.or_else(|| todo.into_iter().find(&predicate)),
-                        remaining,
-                    )
-                },
-            )
-            .into_iter()
-            .filter_map(|o| o)
-            .next()
+        let base_size = max((len as f64).log(2.0).ceil() as usize, 1);
+        let outputs = OrderedAbortingDivisible {
+            real_content: input,
+            position: 0,
+            found: &failed_position,
+        }
+        .with_policy(policy)
+        .by_blocks(sizes.chain(powers(base_size)))
+        .partial_fold(
+            || (Vec::new(), false),
+            |(mut prefix, failed), i, limit| {
+                let (todo, remaining) = i.divide_at(limit);
+                if failed {
+                    // remaining is already cancelled
+                    return ((prefix, failed), remaining);
+                }
+                let position = todo.position;
+                for e in todo.real_content {
+                    if !predicate(&e) {
+                        failed_position.fetch_min(position, atomic::Ordering::Relaxed);
+                        return ((prefix, true), remaining);
+                    }
+                    prefix.push(e);
+                }
+                ((prefix, false), remaining)
+            },
+        );
+        let mut result = Vec::new();
+        // outputs come in order, we stop scheduling blocks after the failure
+        for (prefix, failed) in outputs {
+            result.extend(prefix);
+            if failed {
+                break;
+            }
+        }
+        result
     }
     /// Return if any element e in the iterator is such that
     /// predicate(e) is true.
@@ -158,7 +639,10 @@ pub trait AdaptiveIteratorRunner<I: AdaptiveIterator, S: Iterator<Item = usize>>
     ///
     /// ```
     /// use rayon_adaptive::prelude::*;
-    /// assert!((0..10_000).into_adapt_iter().any(|x| x == 2345))
+    /// assert!((0..10_000).into_adapt_iter().any(|x| x == 2345));
+    /// // we stop early
+    /// assert!((0..u64::MAX).into_adapt_iter().any(|x| x == 2345));
+    /// assert!((7..8).into_adapt_iter().any(|x| x == 7));
     /// ```
     fn any<P>(self, predicate: P) -> bool
     where
@@ -172,37 +656,38 @@ pub trait AdaptiveIteratorRunner<I: AdaptiveIterator, S: Iterator<Item = usize>>
     /// predicate(e) is true.
     /// This algorithm is work efficient and should produce speedups
     /// on fine grain instances.
+    /// All workers stop as soon as one element fails the predicate.
     ///
     /// # Example
     ///
     /// ```
     /// use rayon_adaptive::prelude::*;
-    /// assert!((0..10_000).into_adapt_iter().zip((0..10_000).into_adapt_iter()).all(|(x, y)| x == y))
+    /// assert!((0..10_000).into_adapt_iter().zip((0..10_000).into_adapt_iter()).all(|(x, y)| x == y));
+    /// assert!(!(0..u64::MAX).into_adapt_iter().all(|x| x < 1_000_000));
+    /// assert!((7..8).into_adapt_iter().all(|x| x == 7));
     /// ```
-
     fn all<P>(self, predicate: P) -> bool
     where
         P: Fn(I::Item) -> bool + Sync + Send,
     {
+        let failed = AtomicBool::new(false);
         let (input, policy, sizes) = self.input_policy_sizes();
-        let base_size = std::cmp::min(
-            (input.base_length() as f64).log(2.0).ceil() as usize,
-            input.base_length(),
-        );
+        let base_size = max((input.base_length() as f64).log(2.0).ceil() as usize, 1);
         ActivatedInput {
-            input,
+            input: AbortingDivisible {
+                real_content: input,
+                abort: &failed,
+            },
             folder: Fold {
                 identity_op: || true,
-                fold_op: |s: bool, i: I, limit: usize| {
+                fold_op: |s: bool, i: AbortingDivisible<I>, limit: usize| {
                     let (todo, remaining) = i.divide_at(limit);
-                    (
-                        if s {
-                            todo.into_iter().all(&predicate)
-                        } else {
-                            false
-                        },
-                        remaining,
-                    )
+                    let s = s && todo.into_iter().all(&predicate);
+                    if !s {
+                        // no need for anyone to go on
+                        failed.store(true, atomic::Ordering::Relaxed)
+                    }
+                    (s, remaining)
                 },
                 phantom: PhantomData,
             },
@@ -214,15 +699,35 @@ pub trait AdaptiveIteratorRunner<I: AdaptiveIterator, S: Iterator<Item = usize>>
         .all(|b| b)
     }
     /// Counts the number of items in this adaptive iterator.
+    /// Each sequential block is counted with its iterator's own `count`,
+    /// which is constant time for many of them (slices, ranges).
     ///
     /// Example:
     ///
     /// ```
     /// use rayon_adaptive::prelude::*;
     /// assert_eq!((0..100).into_adapt_iter().filter(|&x| x %2 ==0).count(), 50);
+    /// let v: Vec<u32> = (0..100_000).collect();
+    /// assert_eq!(v.as_slice().into_adapt_iter().count(), 100_000);
+    /// assert_eq!((0..0).into_adapt_iter().count(), 0);
     /// ```
     fn count(self) -> usize {
-        self.fold(|| 0, |s, _| s + 1).reduce(|s1, s2| s1 + s2)
+        let (input, policy, sizes) = self.input_policy_sizes();
+        ActivatedInput {
+            input,
+            folder: Fold {
+                identity_op: || 0,
+                fold_op: |c: usize, i: I, limit: usize| {
+                    let (todo, remaining) = i.divide_at(limit);
+                    (c + todo.into_iter().count(), remaining)
+                },
+                phantom: PhantomData,
+            },
+            policy,
+            sizes,
+            power: PhantomData,
+        }
+        .reduce(|c1, c2| c1 + c2)
     }
     /// Computes the maximum of all the items in the iterator.
     /// If the iterator is empty, None is returned; otherwise, Some(max) is returned.
@@ -256,19 +761,341 @@ pub trait AdaptiveIteratorRunner<I: AdaptiveIterator, S: Iterator<Item = usize>>
         }
         .reduce(std::cmp::max)
     }
-    fn sum<SUM>(self) -> SUM
+    /// Computes the maximum of all the items in the iterator with respect to given
+    /// comparison function.
+    /// If several elements are equally maximum, the last one is returned.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let v = vec![2.5f64, -3.0, 7.25, 1.0];
+    /// let max = v
+    ///     .as_slice()
+    ///     .into_adapt_iter()
+    ///     .max_by(|a, b| a.partial_cmp(b).unwrap());
+    /// assert_eq!(max, Some(&7.25));
+    /// ```
+    fn max_by<F>(self, compare: F) -> Option<I::Item>
     where
-        SUM: std::iter::Sum<I::Item> + Send + Sync + std::ops::Add<Output = SUM>,
+        I::Item: Send + Sync,
+        F: Fn(&I::Item, &I::Item) -> Ordering + Sync + Send,
+    {
+        best_by(self, |a, b| match compare(a, b) {
+            Ordering::Greater => Ordering::Greater,
+            _ => Ordering::Less,
+        })
+    }
+    /// Computes the element giving the maximum value of given function.
+    /// If several elements are equally maximum, the last one is returned.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let max = (0..1_000).into_adapt_iter().max_by_key(|&x| x % 10);
+    /// assert_eq!(max, Some(999));
+    /// ```
+    fn max_by_key<K, F>(self, key: F) -> Option<I::Item>
+    where
+        I::Item: Send + Sync,
+        K: Ord,
+        F: Fn(&I::Item) -> K + Sync + Send,
+    {
+        self.max_by(|a, b| key(a).cmp(&key(b)))
+    }
+    /// Computes the minimum of all the items in the iterator.
+    /// If the iterator is empty, None is returned.
+    /// If several elements are equally minimum, the first one is returned.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// assert_eq!((10..1000).into_adapt_iter().min(), Some(10));
+    /// assert_eq!((0..0).into_adapt_iter().min(), None);
+    /// ```
+    fn min(self) -> Option<I::Item>
+    where
+        I::Item: Ord + Send + Sync,
+    {
+        self.min_by(|a, b| a.cmp(b))
+    }
+    /// Computes the minimum of all the items in the iterator with respect to given
+    /// comparison function.
+    /// If several elements are equally minimum, the first one is returned.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let v = vec![2.5f64, -3.0, 7.25, 1.0];
+    /// let min = v
+    ///     .as_slice()
+    ///     .into_adapt_iter()
+    ///     .min_by(|a, b| a.partial_cmp(b).unwrap());
+    /// assert_eq!(min, Some(&-3.0));
+    /// ```
+    fn min_by<F>(self, compare: F) -> Option<I::Item>
+    where
+        I::Item: Send + Sync,
+        F: Fn(&I::Item, &I::Item) -> Ordering + Sync + Send,
+    {
+        best_by(self, |a, b| match compare(a, b) {
+            Ordering::Greater => Ordering::Less,
+            _ => Ordering::Greater,
+        })
+    }
+    /// Computes the element giving the minimum value of given function.
+    /// If several elements are equally minimum, the first one is returned.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let min = (1..1_000).into_adapt_iter().min_by_key(|&x| x % 10);
+    /// assert_eq!(min, Some(10));
+    /// ```
+    fn min_by_key<K, F>(self, key: F) -> Option<I::Item>
+    where
+        I::Item: Send + Sync,
+        K: Ord,
+        F: Fn(&I::Item) -> K + Sync + Send,
+    {
+        self.min_by(|a, b| key(a).cmp(&key(b)))
+    }
+    /// Reduce all elements with `reduce_op`, which must be associative.
+    /// `identity()` is the neutral element of the reduction and is returned on empty iterators.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let v: Vec<u64> = (1..=1_000).collect();
+    /// let lcm = |a: u64, b: u64| {
+    ///     let (mut x, mut y) = (a, b);
+    ///     while y != 0 {
+    ///         let r = x % y;
+    ///         x = y;
+    ///         y = r;
+    ///     }
+    ///     a / x * b
+    /// };
+    /// let m = v[..20].into_adapt_iter().cloned().reduce(|| 1, lcm);
+    /// assert_eq!(m, 232_792_560);
+    /// let concatenated = v
+    ///     .as_slice()
+    ///     .into_adapt_iter()
+    ///     .map(|e| vec![*e])
+    ///     .reduce(Vec::new, |mut a, b| {
+    ///         a.extend(b);
+    ///         a
+    ///     });
+    /// assert_eq!(concatenated, v);
+    /// assert_eq!((0..0).into_adapt_iter().reduce(|| 42, |a, b| a + b), 42);
+    /// ```
+    fn reduce<ID, OP>(self, identity: ID, reduce_op: OP) -> I::Item
+    where
+        I::Item: Send + Sync,
+        ID: Fn() -> I::Item + Sync + Send,
+        OP: Fn(I::Item, I::Item) -> I::Item + Sync + Send,
     {
         let (input, policy, sizes) = self.input_policy_sizes();
         ActivatedInput {
             input,
             folder: Fold {
-                identity_op: || None.into_iter().sum(),
-                fold_op: |s: SUM, i: I, limit: usize| {
+                identity_op: &identity,
+                fold_op: |r: I::Item, i: I, limit: usize| {
                     let (todo, remaining) = i.divide_at(limit);
-                    let s2 = todo.into_iter().sum();
-                    (s + s2, remaining)
+                    (todo.into_iter().fold(r, &reduce_op), remaining)
+                },
+                phantom: PhantomData,
+            },
+            policy,
+            sizes,
+            power: PhantomData,
+        }
+        .reduce(&reduce_op)
+    }
+    /// Reduce all elements with `reduce_op`, always combining partial results
+    /// in input order. The operation only needs to be associative
+    /// (string concatenation, matrix products, ...).
+    /// Scheduling goes through an `OrderedScheduler` to guarantee it.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// // compose affine functions x -> a*x + b (mod 1_000_007), first one applied first
+    /// let functions: Vec<(u64, u64)> = (0..10_000).map(|i| (i % 7 + 1, i % 13)).collect();
+    /// let compose = |(a1, b1): (u64, u64), (a2, b2): (u64, u64)| {
+    ///     ((a2 * a1) % 1_000_007, (a2 * b1 + b2) % 1_000_007)
+    /// };
+    /// let composed = functions
+    ///     .as_slice()
+    ///     .into_adapt_iter()
+    ///     .cloned()
+    ///     .reduce_ordered(|| (1, 0), compose);
+    /// let sequential = functions.iter().cloned().fold((1, 0), compose);
+    /// assert_eq!(composed, sequential);
+    /// ```
+    fn reduce_ordered<ID, OP>(self, identity: ID, reduce_op: OP) -> I::Item
+    where
+        I::Item: Send + Sync,
+        ID: Fn() -> I::Item + Sync + Send,
+        OP: Fn(I::Item, I::Item) -> I::Item + Sync + Send,
+    {
+        let (input, policy, sizes) = self.input_policy_sizes();
+        ActivatedInput {
+            input,
+            folder: Fold {
+                identity_op: &identity,
+                fold_op: |r: I::Item, i: I, limit: usize| {
+                    let (todo, remaining) = i.divide_at(limit);
+                    (todo.into_iter().fold(r, &reduce_op), remaining)
+                },
+                phantom: PhantomData,
+            },
+            policy,
+            sizes,
+            power: PhantomData,
+        }
+        .reduce_ordered_with(&policy, &reduce_op)
+    }
+    /// Fold elements with a fallible `fold_op` and combine partial results with a
+    /// fallible `reduce_op`. Works with `Option` or `Result`.
+    /// As soon as one operation fails all workers stop, even in the middle of
+    /// their sequential blocks, and one of the failures is returned.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let checked_sum = |a: u32, b: u32| a.checked_add(b);
+    /// let s = (0..1_000u32)
+    ///     .into_adapt_iter()
+    ///     .try_fold(|| 0, checked_sum, checked_sum);
+    /// assert_eq!(s, Some(999 * 500));
+    /// assert_eq!((0..1_000_000u32).into_adapt_iter().try_fold(|| 0, checked_sum, checked_sum), None);
+    /// let r: Result<u64, u64> = (0..u64::MAX)
+    ///     .into_adapt_iter()
+    ///     .try_fold(|| 0, |s, e| if e == 12_345 { Err(e) } else { Ok(s + e) }, |a, b| Ok(a + b));
+    /// assert_eq!(r, Err(12_345));
+    /// ```
+    fn try_fold<T, R, ID, F, RF>(self, identity: ID, fold_op: F, reduce_op: RF) -> R
+    where
+        R: Fallible<Output = T>,
+        T: Send + Sync,
+        R::Residual: Send + Sync,
+        ID: Fn() -> T + Sync + Send,
+        F: Fn(T, I::Item) -> R + Sync + Send,
+        RF: Fn(T, T) -> R + Sync + Send,
+    {
+        let failed = AtomicBool::new(false);
+        let check = |result: Result<T, R::Residual>| {
+            if result.is_err() {
+                failed.store(true, atomic::Ordering::Relaxed)
+            }
+            result
+        };
+        let (input, policy, sizes) = self.input_policy_sizes();
+        let result = ActivatedInput {
+            input: AbortingDivisible {
+                real_content: input,
+                abort: &failed,
+            },
+            folder: Fold {
+                identity_op: || Ok(identity()),
+                fold_op: |r: Result<T, R::Residual>, i: AbortingDivisible<I>, limit: usize| {
+                    let (todo, remaining) = i.divide_at(limit);
+                    let r = r.and_then(|t| {
+                        check(
+                            todo.into_iter()
+                                .take_while(|_| !failed.load(atomic::Ordering::Relaxed))
+                                .try_fold(t, |t, e| fold_op(t, e).into_result()),
+                        )
+                    });
+                    (r, remaining)
+                },
+                phantom: PhantomData,
+            },
+            policy,
+            sizes,
+            power: PhantomData,
+        }
+        // pieces stopped early still produce partial outputs but they are
+        // always reduced with the failure which stopped them
+        .reduce(|left, right| match (left, right) {
+            (Ok(left), Ok(right)) => check(reduce_op(left, right).into_result()),
+            (Err(residual), _) | (_, Err(residual)) => Err(residual),
+        });
+        match result {
+            Ok(output) => R::from_output(output),
+            Err(residual) => R::from_residual(residual),
+        }
+    }
+    /// Reduce fallible elements (`Option` or `Result`) with a fallible `reduce_op`.
+    /// Stops everyone on the first failed element or reduction.
+    /// `identity` must be a neutral element for `reduce_op`.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let texts: Vec<String> = (0..1_000).map(|i| i.to_string()).collect();
+    /// let s = texts
+    ///     .as_slice()
+    ///     .into_adapt_iter()
+    ///     .map(|t| t.parse::<u64>())
+    ///     .try_reduce(|| 0, |a, b| Ok(a + b));
+    /// assert_eq!(s, Ok(999 * 500));
+    /// let m = (1..1_000u64)
+    ///     .into_adapt_iter()
+    ///     .map(|i| 1_000_000_000u64.checked_div(i % 500))
+    ///     .try_reduce(|| 0, |a, b| Some(std::cmp::max(a, b)));
+    /// assert_eq!(m, None);
+    /// ```
+    fn try_reduce<T, ID, OP>(self, identity: ID, reduce_op: OP) -> I::Item
+    where
+        I::Item: Fallible<Output = T>,
+        T: Send + Sync,
+        <I::Item as Fallible>::Residual: Send + Sync,
+        ID: Fn() -> T + Sync + Send,
+        OP: Fn(T, T) -> I::Item + Sync + Send,
+    {
+        self.try_fold(
+            identity,
+            |t, e| match e.into_result() {
+                Ok(e) => reduce_op(t, e),
+                Err(residual) => I::Item::from_residual(residual),
+            },
+            &reduce_op,
+        )
+    }
+    /// Sum all elements.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let s: u64 = (0..10_000u64).into_adapt_iter().sum();
+    /// assert_eq!(s, 9_999 * 5_000);
+    /// let s: f64 = vec![0.5; 1_000].as_slice().into_adapt_iter().sum();
+    /// assert_eq!(s, 500.0);
+    /// ```
+    fn sum<SUM>(self) -> SUM
+    where
+        SUM: std::iter::Sum<I::Item> + Send + Sync + std::ops::Add<Output = SUM>,
+    {
+        let (input, policy, sizes) = self.input_policy_sizes();
+        ActivatedInput {
+            input,
+            folder: Fold {
+                identity_op: || None.into_iter().sum(),
+                fold_op: |s: SUM, i: I, limit: usize| {
+                    let (todo, remaining) = i.divide_at(limit);
+                    let s2 = todo.into_iter().sum();
+                    (s + s2, remaining)
                 },
                 phantom: PhantomData,
             },
@@ -278,6 +1105,39 @@ pub trait AdaptiveIteratorRunner<I: AdaptiveIterator, S: Iterator<Item = usize>>
         }
         .reduce(|a, b| a + b)
     }
+    /// Multiply all elements.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let factorial: u64 = (1..=20u64).into_adapt_iter().product();
+    /// assert_eq!(factorial, 2_432_902_008_176_640_000);
+    /// let p: f64 = vec![1.001f64; 1_000].as_slice().into_adapt_iter().product();
+    /// assert!((p - 1.001f64.powi(1_000)).abs() < 1e-9);
+    /// ```
+    fn product<PRODUCT>(self) -> PRODUCT
+    where
+        PRODUCT: std::iter::Product<I::Item> + Send + Sync + std::ops::Mul<Output = PRODUCT>,
+    {
+        let (input, policy, sizes) = self.input_policy_sizes();
+        ActivatedInput {
+            input,
+            folder: Fold {
+                identity_op: || None.into_iter().product(),
+                fold_op: |p: PRODUCT, i: I, limit: usize| {
+                    let (todo, remaining) = i.divide_at(limit);
+                    let p2 = todo.into_iter().product();
+                    (p * p2, remaining)
+                },
+                phantom: PhantomData,
+            },
+            policy,
+            sizes,
+            power: PhantomData,
+        }
+        .reduce(|a, b| a * b)
+    }
 
     /// Apply *op* on each element.
     ///
@@ -314,16 +1174,427 @@ pub trait AdaptiveIteratorRunner<I: AdaptiveIterator, S: Iterator<Item = usize>>
         }
         .reduce(|_, _| ())
     }
+    /// Apply *op* on each element together with some mutable state.
+    /// Each task works on its own clone of `init`.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// use std::sync::mpsc::channel;
+    /// let (sender, receiver) = channel();
+    /// (0..1_000u32)
+    ///     .into_adapt_iter()
+    ///     .for_each_with(sender, |s, x| s.send(x).unwrap());
+    /// let mut received: Vec<u32> = receiver.iter().collect();
+    /// received.sort();
+    /// assert_eq!(received, (0..1_000).collect::<Vec<u32>>());
+    /// ```
+    fn for_each_with<T, OP>(self, init: T, op: OP)
+    where
+        T: Clone + Send + Sync,
+        OP: Fn(&mut T, I::Item) + Sync + Send,
+    {
+        let (input, policy, sizes) = self.input_policy_sizes();
+        ActivatedInput {
+            input,
+            folder: Fold {
+                identity_op: || init.clone(),
+                fold_op: |mut t: T, i: I, limit: usize| {
+                    let (todo, remaining) = i.divide_at(limit);
+                    todo.into_iter().for_each(|e| op(&mut t, e));
+                    (t, remaining)
+                },
+                phantom: PhantomData,
+            },
+            policy,
+            sizes,
+            power: PhantomData,
+        }
+        .reduce(|t, _| t);
+    }
 
+    /// Collect turn an `AdaptiveIterator` into a collection.
+    /// Collecting comes with different algorithms for each power of the iterator.
+    /// Vectors are filled in place for indexed iterators and built out of
+    /// one vector per task (moving data twice) for blocked ones.
+    ///
+    /// Example
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// use std::collections::{HashMap, HashSet};
+    /// let v:Vec<_> = (0..10_000).into_adapt_iter().map(|i| i+1).collect();
+    /// let vseq:Vec<_> = (0..=10_000).skip(1).collect();
+    /// assert_eq!(v, vseq);
+    /// let v:Vec<_> = (0..10_000).into_adapt_iter().filter(|&i| i%2 == 0).collect();
+    /// let vseq:Vec<_> = (0..5_000).map(|i| i*2).collect();
+    /// assert_eq!(v, vseq);
+    /// let squares: HashMap<u32, u32> = (0..1_000).into_adapt_iter().map(|i| (i, i * i)).collect();
+    /// assert_eq!(squares[&12], 144);
+    /// let remainders: HashSet<u32> = (0..1_000).into_adapt_iter().map(|i| i % 7).collect();
+    /// assert_eq!(remainders.len(), 7);
+    /// let s: String = "hello world".adapt_chars().filter(|c| *c != 'o').collect();
+    /// assert_eq!(s, "hell wrld");
+    /// let texts = vec!["1", "2", "x"];
+    /// let parsed: Result<Vec<u32>, _> = texts.as_slice().into_adapt_iter().map(|s| s.parse::<u32>()).collect();
+    /// assert!(parsed.is_err());
+    /// let all: Option<Vec<u32>> = (1..100u32).into_adapt_iter().map(|i| 100u32.checked_div(i)).collect();
+    /// assert_eq!(all.map(|v| v.len()), Some(99));
+    /// ```
+    fn collect<C>(self) -> C
+    where
+        I::Item: Send,
+        C: FromAdaptiveIterator<I::Item, I::Power>,
+    {
+        FromAdaptiveIterator::from_adapt_iter(self)
+    }
+    /// Collect the running results of `op` (inclusive prefix): the `i`-th output
+    /// is the reduction of all elements up to the `i`-th one included.
+    /// `op` must be associative.
+    /// We work in two passes: elements get collected and then the adaptive
+    /// prefix algorithm runs on the vector (see `adaptive_prefix`).
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let sums = (1..=10_000u64).into_adapt_iter().scan_inclusive(|a, b| a + b);
+    /// assert_eq!(sums[9], 55);
+    /// assert_eq!(sums[9_999], 10_000 * 10_001 / 2);
+    /// let words: Vec<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+    /// let prefixes = words
+    ///     .as_slice()
+    ///     .into_adapt_iter()
+    ///     .cloned()
+    ///     .scan_inclusive(|a, b| format!("{}{}", a, b));
+    /// assert_eq!(prefixes, vec!["a", "ab", "abc"]);
+    /// ```
+    fn scan_inclusive<OP>(self, op: OP) -> Vec<I::Item>
+    where
+        I::Item: Clone + Send + Sync,
+        Vec<I::Item>: FromAdaptiveIterator<I::Item, I::Power>,
+        OP: Fn(&I::Item, &I::Item) -> I::Item + Sync,
+    {
+        let mut prefix: Vec<I::Item> = self.collect();
+        if !prefix.is_empty() {
+            // the prefix algorithm gets the current element first
+            adaptive_prefix(&mut prefix, |current, previous| op(previous, current));
+        }
+        prefix
+    }
+    /// Collect the running results of `op` excluding the current element
+    /// (exclusive prefix): the first output is `identity()` and the `i`-th one is
+    /// the reduction of all elements before the `i`-th.
+    /// `op` must be associative and `identity()` neutral for it.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// // offsets of variable sized records
+    /// let sizes = vec![3usize, 1, 4, 1, 5];
+    /// let offsets = sizes.as_slice().into_adapt_iter().cloned().scan_exclusive(|| 0, |a, b| a + b);
+    /// assert_eq!(offsets, vec![0, 3, 4, 8, 9]);
+    /// let offsets = (1..100_001u64).into_adapt_iter().scan_exclusive(|| 0, |a, b| a + b);
+    /// assert_eq!(offsets[0], 0);
+    /// assert_eq!(offsets[99_999], 99_999 * 50_000);
+    /// ```
+    fn scan_exclusive<ID, OP>(self, identity: ID, op: OP) -> Vec<I::Item>
+    where
+        I::Item: Clone + Send + Sync,
+        Vec<I::Item>: FromAdaptiveIterator<I::Item, I::Power>,
+        ID: Fn() -> I::Item,
+        OP: Fn(&I::Item, &I::Item) -> I::Item + Sync,
+    {
+        let mut elements: Vec<I::Item> = self.collect();
+        // last element is in no output
+        if elements.pop().is_none() {
+            return elements;
+        }
+        let length = elements.len() + 1;
+        let mut prefix = Vec::with_capacity(length);
+        prefix.push(identity());
+        // all other elements are written (in parallel) one place further
+        (&mut prefix.spare_capacity_mut()[..length - 1])
+            .into_adapt_iter()
+            .zip(elements.as_slice().into_adapt_iter())
+            .for_each(|(destination, element)| {
+                destination.write(element.clone());
+            });
+        unsafe {
+            prefix.set_len(length);
+        }
+        adaptive_prefix(&mut prefix, |current, previous| op(previous, current));
+        prefix
+    }
+    /// Collect elements, removing consecutive duplicates.
+    /// See `dedup_by`.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let unique = (0..100_000u32).into_adapt_iter().map(|x| x / 10).dedup();
+    /// assert_eq!(unique, (0..10_000).collect::<Vec<u32>>());
+    /// ```
+    fn dedup(self) -> Vec<I::Item>
+    where
+        I::Item: PartialEq + Send + Sync,
+    {
+        self.dedup_by(|a, b| a == b)
+    }
+    /// Collect elements, removing consecutive elements which are the `same`
+    /// as the element kept before them. `same` should be an equivalence.
+    /// Each task deduplicates its own elements and duplicates across tasks
+    /// get removed when reducing their outputs (in order).
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let words = vec!["apple", "Apple", "APPLE", "pear", "Pear", "apple"];
+    /// let unique = words
+    ///     .as_slice()
+    ///     .into_adapt_iter()
+    ///     .dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    /// assert_eq!(unique, vec![&"apple", &"pear", &"apple"]);
+    /// ```
+    fn dedup_by<F>(self, same: F) -> Vec<I::Item>
+    where
+        I::Item: Send + Sync,
+        F: Fn(&I::Item, &I::Item) -> bool + Sync,
+    {
+        let (input, policy, sizes) = self.input_policy_sizes();
+        let pieces = ActivatedInput {
+            input,
+            folder: Fold {
+                identity_op: || {
+                    let mut list = LinkedList::new();
+                    list.push_back(VecDeque::new());
+                    list
+                },
+                fold_op: |mut list: LinkedList<VecDeque<I::Item>>, i: I, limit: usize| {
+                    let (todo, remaining) = i.divide_at(limit);
+                    let output = list.back_mut().unwrap();
+                    for e in todo {
+                        if output.back().map(|last| !same(last, &e)).unwrap_or(true) {
+                            output.push_back(e)
+                        }
+                    }
+                    (list, remaining)
+                },
+                phantom: PhantomData,
+            },
+            policy,
+            sizes,
+            power: PhantomData,
+        }
+        .reduce_ordered_with(&policy, |mut left, mut right| {
+            let last = left.iter().rev().find_map(|v| v.back());
+            let first = right.iter_mut().find(|v| !v.is_empty());
+            if let (Some(last), Some(first)) = (last, first) {
+                if same(last, &first[0]) {
+                    first.pop_front();
+                }
+            }
+            left.append(&mut right);
+            left
+        });
+        let mut output = Vec::with_capacity(pieces.iter().map(|v| v.len()).sum());
+        for v in pieces {
+            output.extend(v);
+        }
+        output
+    }
+    /// Collect groups of consecutive elements with equal keys.
+    /// Each task groups its own elements and groups spanning several tasks
+    /// get stitched back together when reducing their outputs (in order).
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let groups = (0..100_000u32).into_adapt_iter().chunk_by(|&x| x / 1_000);
+    /// assert_eq!(groups.len(), 100);
+    /// assert!(groups.iter().all(|g| g.len() == 1_000));
+    /// let log = vec!["ok", "ok", "error", "ok", "error", "error"];
+    /// let runs: Vec<(&str, usize)> = log
+    ///     .as_slice()
+    ///     .into_adapt_iter()
+    ///     .chunk_by(|&&l| l)
+    ///     .into_iter()
+    ///     .map(|g| (*g[0], g.len()))
+    ///     .collect();
+    /// assert_eq!(runs, vec![("ok", 2), ("error", 1), ("ok", 1), ("error", 2)]);
+    /// ```
+    fn chunk_by<K, F>(self, key: F) -> Vec<Vec<I::Item>>
+    where
+        I::Item: Send + Sync,
+        K: PartialEq + Send + Sync,
+        F: Fn(&I::Item) -> K + Sync,
+    {
+        let (input, policy, sizes) = self.input_policy_sizes();
+        // groups are stored with their key so we compute each key only once
+        let pieces = ActivatedInput {
+            input,
+            folder: Fold {
+                identity_op: || {
+                    let mut list = LinkedList::new();
+                    list.push_back(VecDeque::new());
+                    list
+                },
+                fold_op: |mut list: LinkedList<VecDeque<(K, Vec<I::Item>)>>, i: I, limit: usize| {
+                    let (todo, remaining) = i.divide_at(limit);
+                    let groups = list.back_mut().unwrap();
+                    for e in todo {
+                        let k = key(&e);
+                        match groups.back_mut() {
+                            Some((group_key, group)) if *group_key == k => group.push(e),
+                            _ => groups.push_back((k, vec![e])),
+                        }
+                    }
+                    (list, remaining)
+                },
+                phantom: PhantomData,
+            },
+            policy,
+            sizes,
+            power: PhantomData,
+        }
+        .reduce_ordered_with(&policy, |mut left, mut right| {
+            let last = left.iter_mut().rev().find_map(|groups| groups.back_mut());
+            let first = right.iter_mut().find(|groups| !groups.is_empty());
+            if let (Some((last_key, last)), Some(first)) = (last, first) {
+                if *last_key == first[0].0 {
+                    last.extend(first.pop_front().unwrap().1);
+                }
+            }
+            left.append(&mut right);
+            left
+        });
+        let mut output = Vec::with_capacity(pieces.iter().map(|groups| groups.len()).sum());
+        for groups in pieces {
+            output.extend(groups.into_iter().map(|(_, group)| group));
+        }
+        output
+    }
+    /// Collect pairs into two vectors in a single pass, keeping their order.
+    /// Each task fills its own pair of vectors which get concatenated at the end.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let (squares, cubes): (Vec<u64>, Vec<u64>) = (0..10_000u64)
+    ///     .into_adapt_iter()
+    ///     .map(|x| (x * x, x * x * x))
+    ///     .unzip();
+    /// assert_eq!(squares[100], 10_000);
+    /// assert_eq!(cubes[100], 1_000_000);
+    /// assert_eq!(cubes.len(), 10_000);
+    /// assert!(squares.windows(2).all(|w| w[0] < w[1]));
+    /// ```
+    fn unzip<A, B>(self) -> (Vec<A>, Vec<B>)
+    where
+        I: AdaptiveIterator<Item = (A, B)>,
+        A: Send + Sync,
+        B: Send + Sync,
+    {
+        split_in_two(self, |(a_output, b_output), (a, b)| {
+            a_output.push(a);
+            b_output.push(b);
+        })
+    }
+    /// Split elements into those satisfying the predicate and the others,
+    /// in a single pass and keeping their order.
+    /// Like `unzip`, each task fills its own pair of vectors and all pairs
+    /// get concatenated in order at the end.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let (even, odd): (Vec<u32>, Vec<u32>) =
+    ///     (0..10_000).into_adapt_iter().partition(|&x| x % 2 == 0);
+    /// assert_eq!(even, (0..5_000).map(|x| 2 * x).collect::<Vec<u32>>());
+    /// assert_eq!(odd, (0..5_000).map(|x| 2 * x + 1).collect::<Vec<u32>>());
+    /// ```
+    fn partition<P>(self, predicate: P) -> (Vec<I::Item>, Vec<I::Item>)
+    where
+        P: Fn(&I::Item) -> bool + Sync + Send,
+        I::Item: Send + Sync,
+    {
+        split_in_two(self, |(accepted, rejected), e| {
+            if predicate(&e) {
+                accepted.push(e)
+            } else {
+                rejected.push(e)
+            }
+        })
+    }
+    /// Turn each element into a `Left` or a `Right` value and collect both sides,
+    /// in a single pass and keeping their order.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// use rayon_adaptive::Either;
+    /// let texts: Vec<String> = (0..1_000)
+    ///     .map(|i| if i % 10 == 0 { format!("x{}", i) } else { i.to_string() })
+    ///     .collect();
+    /// let (numbers, errors): (Vec<u32>, Vec<&String>) = texts
+    ///     .as_slice()
+    ///     .into_adapt_iter()
+    ///     .partition_map(|t| match t.parse::<u32>() {
+    ///         Ok(n) => Either::Left(n),
+    ///         Err(_) => Either::Right(t),
+    ///     });
+    /// assert_eq!(numbers.len(), 900);
+    /// assert!(numbers.windows(2).all(|w| w[0] < w[1]));
+    /// assert_eq!(errors[1], "x10");
+    /// assert!(errors.iter().zip((0..1_000).step_by(10)).all(|(e, i)| **e == format!("x{}", i)));
+    /// ```
+    fn partition_map<L, R, F>(self, partition_op: F) -> (Vec<L>, Vec<R>)
+    where
+        F: Fn(I::Item) -> Either<L, R> + Sync + Send,
+        L: Send + Sync,
+        R: Send + Sync,
+    {
+        split_in_two(self, |(left, right), e| match partition_op(e) {
+            Either::Left(l) => left.push(l),
+            Either::Right(r) => right.push(r),
+        })
+    }
+    /// Fold elements sequentially inside each piece, starting from `identity()`.
+    /// The partial results still need to be reduced (see `ActivatedInput::reduce`).
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// // count lengths of all words
+    /// let words: Vec<String> = (0..1_000).map(|i| i.to_string()).collect();
+    /// let total = words
+    ///     .as_slice()
+    ///     .into_adapt_iter()
+    ///     .fold(String::new, |mut s, w| {
+    ///         s.push_str(w);
+    ///         s
+    ///     })
+    ///     .map(|s| s.len())
+    ///     .reduce(|a, b| a + b);
+    /// assert_eq!(total, 10 + 90 * 2 + 900 * 3);
+    /// ```
     fn fold<IO, ID, F>(
         self,
         identity: ID,
         fold_op: F,
     ) -> ActivatedInput<AdaptiveIteratorFold<I, IO, ID, F>, S, BlockedOrMore>
     where
-        IO: Send + Sync + Clone,
-        ID: Fn() -> IO + Sync + Send + Clone,
-        F: Fn(IO, I::Item) -> IO + Sync + Send + Clone,
+        IO: Send + Sync,
+        ID: Fn() -> IO + Sync + Send,
+        F: Fn(IO, I::Item) -> IO + Sync + Send,
     {
         let (input, policy, sizes) = self.input_policy_sizes();
         ActivatedInput {
@@ -338,59 +1609,204 @@ pub trait AdaptiveIteratorRunner<I: AdaptiveIterator, S: Iterator<Item = usize>>
             power: PhantomData,
         }
     }
-}
-
-/// Specializations of AdaptiveIteratorRunner.
-pub trait AdaptiveIndexedIteratorRunner<I: AdaptiveIndexedIterator, S: Iterator<Item = usize>>:
-    AdaptiveRunner<I, S>
-{
-    /// Collect turn an `AdaptiveIterator` into a collection.
-    /// As of now it is only implemented for `Vec`.
-    /// Collecting comes with different algorithms for each Divisibility type
-    /// (`Divisible`, `DivisibleIntoBlocks`, `DivisibleAtIndex`)
-    /// This version is the `DivisibleAtIndex` version and will incur very little overhead.
+    /// Fold elements sequentially inside each piece, starting from a clone of `init`.
+    /// A worker processing consecutive blocks without being stolen keeps folding
+    /// into the same accumulator so `init` only gets cloned when work is stolen.
+    ///
+    /// Example:
     ///
-    /// Example
     /// ```
     /// use rayon_adaptive::prelude::*;
-    /// let v:Vec<_> = (0..10_000).into_adapt_iter().map(|i| i+1).collect();
-    /// let vseq:Vec<_> = (0..=10_000).skip(1).collect();
-    /// assert_eq!(v, vseq)
+    /// let histograms = (0..10_000u32)
+    ///     .into_adapt_iter()
+    ///     .fold_with(vec![0u32; 10], |mut h, e| {
+    ///         h[(e % 10) as usize] += 1;
+    ///         h
+    ///     })
+    ///     .reduce(|mut a, b| {
+    ///         a.iter_mut().zip(b).for_each(|(x, y)| *x += y);
+    ///         a
+    ///     });
+    /// assert_eq!(histograms, vec![1_000; 10]);
     /// ```
-    fn collect<C>(self) -> C
+    fn fold_with<T, F>(
+        self,
+        init: T,
+        fold_op: F,
+    ) -> ActivatedInput<AdaptiveIteratorFoldWith<I, T, F>, S, BlockedOrMore>
     where
-        I::Item: Send,
-        C: FromAdaptiveIndexedIterator<I::Item>,
+        T: Clone + Send + Sync,
+        F: Fn(T, I::Item) -> T + Sync + Send,
     {
-        FromAdaptiveIndexedIterator::from_adapt_iter(self)
+        let (input, policy, sizes) = self.input_policy_sizes();
+        ActivatedInput {
+            input,
+            folder: AdaptiveIteratorFoldWith {
+                init,
+                fold_op,
+                phantom: PhantomData,
+            },
+            policy,
+            sizes,
+            power: PhantomData,
+        }
     }
 }
-pub trait AdaptiveBlockedIteratorRunner<
-    I: AdaptiveIterator<Power = BlockedPower>,
+
+// Push elements into two vectors, using one pair of vectors per task
+// which are all concatenated (in order) at the end.
+fn split_in_two<I, S, R, A, B, F>(runner: R, push: F) -> (Vec<A>, Vec<B>)
+where
+    I: AdaptiveIterator,
     S: Iterator<Item = usize>,
->: AdaptiveRunner<I, S>
+    R: AdaptiveRunner<I, S>,
+    A: Send + Sync,
+    B: Send + Sync,
+    F: Fn(&mut (Vec<A>, Vec<B>), I::Item) + Sync,
 {
-    /// Collect turn an `AdaptiveIterator` into a collection.
-    /// As of now it is only implemented for `Vec`.
-    /// Collecting comes with different algorithms for each Divisibility type
-    /// (`Divisible`, `DivisibleIntoBlocks`, `DivisibleAtIndex`)
-    /// This version is the `DivisibleIntoBlocks` version and will incur very some overhead
-    /// moving data twice.
+    let (input, policy, sizes) = runner.input_policy_sizes();
+    let pieces = ActivatedInput {
+        input,
+        folder: Fold {
+            identity_op: || {
+                let mut list = LinkedList::new();
+                list.push_back((Vec::new(), Vec::new()));
+                list
+            },
+            fold_op: |mut list: LinkedList<(Vec<A>, Vec<B>)>, i: I, limit: usize| {
+                let (todo, remaining) = i.divide_at(limit);
+                let outputs = list.back_mut().unwrap();
+                todo.into_iter().for_each(|e| push(outputs, e));
+                (list, remaining)
+            },
+            phantom: PhantomData,
+        },
+        policy,
+        sizes,
+        power: PhantomData,
+    }
+    .reduce_ordered_with(&policy, |mut left, mut right| {
+        left.append(&mut right);
+        left
+    });
+    let a_length = pieces.iter().map(|(a, _)| a.len()).sum();
+    let b_length = pieces.iter().map(|(_, b)| b.len()).sum();
+    let mut output = (Vec::with_capacity(a_length), Vec::with_capacity(b_length));
+    for (a, b) in pieces {
+        output.0.extend(a);
+        output.1.extend(b);
+    }
+    output
+}
+
+// Search pieces in order, cancelling all pieces after the first success.
+// `search` gets each piece together with its position.
+fn search_first<I, S, R, O, F>(runner: R, search: F) -> Option<O>
+where
+    I: AdaptiveIterator,
+    S: Iterator<Item = usize>,
+    R: AdaptiveRunner<I, S>,
+    O: Send + Sync,
+    F: Fn(I, usize) -> Option<O> + Sync,
+{
+    let found_position = AtomicUsize::new(std::usize::MAX);
+    let (input, policy, sizes) = runner.input_policy_sizes();
+    let len = input.base_length();
+    let base_size = max((len as f64).log(2.0).ceil() as usize, 1);
+    OrderedAbortingDivisible {
+        real_content: input,
+        position: 0,
+        found: &found_position,
+    }
+    .with_policy(policy)
+    .by_blocks(sizes.chain(powers(base_size)))
+    .partial_fold(
+        || None,
+        |found, i, limit| {
+            let (todo, remaining) = i.divide_at(limit);
+            if found.is_some() {
+                // remaining is already cancelled
+                return (found, remaining);
+            }
+            let position = todo.position;
+            let new_found = search(todo.real_content, position);
+            if new_found.is_some() {
+                found_position.fetch_min(position, atomic::Ordering::Relaxed);
+            }
+            (new_found, remaining)
+        },
+    )
+    .into_iter()
+    .filter_map(|o| o)
+    .next()
+}
+
+// Keep first of two consecutive elements if `first_wins` says `Greater`.
+fn best_by<I, S, R, F>(runner: R, first_wins: F) -> Option<I::Item>
+where
+    I: AdaptiveIterator,
+    S: Iterator<Item = usize>,
+    R: AdaptiveRunner<I, S>,
+    I::Item: Send + Sync,
+    F: Fn(&I::Item, &I::Item) -> Ordering + Sync + Send,
+{
+    let best = |a: Option<I::Item>, b: Option<I::Item>| match (a, b) {
+        (Some(a), Some(b)) => {
+            if first_wins(&a, &b) == Ordering::Greater {
+                Some(a)
+            } else {
+                Some(b)
+            }
+        }
+        (a, None) => a,
+        (None, b) => b,
+    };
+    let (input, policy, sizes) = runner.input_policy_sizes();
+    ActivatedInput {
+        input,
+        folder: Fold {
+            identity_op: || None,
+            fold_op: |previous_best, i: I, limit: usize| {
+                let (todo, remaining) = i.divide_at(limit);
+                let new_best = todo.into_iter().fold(None, |b, e| best(b, Some(e)));
+                (best(previous_best, new_best), remaining)
+            },
+            phantom: PhantomData,
+        },
+        policy,
+        sizes,
+        power: PhantomData,
+    }
+    .reduce(&best)
+}
+
+/// Specializations of AdaptiveIteratorRunner.
+pub trait AdaptiveIndexedIteratorRunner<I: AdaptiveIndexedIterator, S: Iterator<Item = usize>>:
+    AdaptiveRunner<I, S>
+{
+    /// Return the index of the first element e such that predicate(e) is true.
+    /// Like `find_first`, all pieces after a match get cancelled.
+    ///
+    /// Example:
     ///
-    /// Example
     /// ```
     /// use rayon_adaptive::prelude::*;
-    /// let v:Vec<_> = (0..10_000).into_adapt_iter().filter(|&i| i%2 == 0).collect();
-    /// let vseq:Vec<_> = (0..5_000).map(|i| i*2).collect();
-    /// assert_eq!(v, vseq)
+    /// let v: Vec<u32> = (0..100_000).map(|i| i % 5_000).collect();
+    /// let position = v.as_slice().into_adapt_iter().position_first(|&e| e == 4_321);
+    /// assert_eq!(position, Some(4_321));
+    /// assert_eq!((0..1_000).into_adapt_iter().position_first(|x| x < 0), None);
+    /// assert_eq!((7..8).into_adapt_iter().position_first(|x| x == 7), Some(0));
     /// ```
-
-    fn collect<C>(self) -> C
+    fn position_first<P>(self, predicate: P) -> Option<usize>
     where
-        I::Item: Send,
-        C: FromAdaptiveBlockedIterator<I::Item>,
+        P: Fn(I::Item) -> bool + Sync + Send,
     {
-        FromAdaptiveBlockedIterator::from_adapt_iter(self)
+        search_first(self, |piece: I, start| {
+            piece
+                .into_iter()
+                .position(&predicate)
+                .map(|index| start + index)
+        })
     }
 }
 
@@ -405,12 +1821,3 @@ impl<I: AdaptiveIndexedIterator, S: Iterator<Item = usize>> AdaptiveIndexedItera
 {
 }
 impl<I: AdaptiveIndexedIterator> AdaptiveIndexedIteratorRunner<I, Empty<usize>> for I {}
-
-impl<I: AdaptiveIterator<Power = BlockedPower>, S: Iterator<Item = usize>>
-    AdaptiveBlockedIteratorRunner<I, S> for ParametrizedInput<I, S>
-{
-}
-impl<I: AdaptiveIterator<Power = BlockedPower>> AdaptiveBlockedIteratorRunner<I, Empty<usize>>
-    for I
-{
-}
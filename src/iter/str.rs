@@ -1,6 +1,5 @@
 use crate::prelude::*;
 use crate::traits::BlockedPower;
-use itertools::Itertools;
 use std::str::Chars;
 
 /// Adaptive iterator on characters of strings.
@@ -16,27 +15,14 @@ impl<'a> IntoIterator for AdaptiveChars<'a> {
     }
 }
 
-impl<'a> AdaptiveChars<'a> {
-    fn find_splitting_index_around(&self, start_index: usize) -> usize {
-        let len = self.real_str.len();
-        let higher_indices = start_index..len;
-        let lower_indices = (0..start_index).rev();
-        lower_indices
-            .interleave(higher_indices)
-            .find(|&i| self.real_str.is_char_boundary(i))
-            .unwrap_or(len) // if we cannot split we just generate an empty slice
-    }
-}
-
 impl<'a> Divisible for AdaptiveChars<'a> {
     type Power = BlockedPower;
     fn base_length(&self) -> usize {
-        self.real_str.len()
+        self.real_str.base_length()
     }
     /// Pre-condition: self.can_be_divided() is true.
     fn divide(self) -> (Self, Self) {
-        let index = self.find_splitting_index_around(self.real_str.len() / 2);
-        let (left, right) = self.real_str.split_at(index);
+        let (left, right) = self.real_str.divide();
         (
             AdaptiveChars { real_str: left },
             AdaptiveChars { real_str: right },
@@ -47,8 +33,7 @@ impl<'a> Divisible for AdaptiveChars<'a> {
 impl<'a> DivisibleIntoBlocks for AdaptiveChars<'a> {
     /// Pre-condition: self.can_be_divided() is true.
     fn divide_at(self, index: usize) -> (Self, Self) {
-        let index = self.find_splitting_index_around(index);
-        let (left, right) = self.real_str.split_at(index);
+        let (left, right) = self.real_str.divide_at(index);
         (
             AdaptiveChars { real_str: left },
             AdaptiveChars { real_str: right },
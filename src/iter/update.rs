@@ -0,0 +1,53 @@
+use crate::prelude::*;
+use derive_divisible::{Divisible, DivisibleIntoBlocks};
+
+/// Adaptive iterator mutating each element before passing it on.
+/// See `AdaptiveIterator::update`.
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+#[derive(Divisible, DivisibleIntoBlocks)]
+#[power(I::Power)]
+pub struct Update<I: AdaptiveIterator, F: Clone + Send + Sync> {
+    pub(crate) base: I,
+    #[divide_by(clone)]
+    pub(crate) update_op: F,
+}
+
+/// Sequential iterator for `Update` blocks.
+pub struct UpdateIter<J, F> {
+    iter: J,
+    update_op: F,
+}
+
+impl<J: Iterator, F: Fn(&mut J::Item)> Iterator for UpdateIter<J, F> {
+    type Item = J::Item;
+    fn next(&mut self) -> Option<J::Item> {
+        let mut e = self.iter.next()?;
+        (self.update_op)(&mut e);
+        Some(e)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I: AdaptiveIterator, F: Fn(&mut I::Item) + Clone + Send + Sync> IntoIterator for Update<I, F> {
+    type Item = I::Item;
+    type IntoIter = UpdateIter<I::IntoIter, F>;
+    fn into_iter(self) -> Self::IntoIter {
+        UpdateIter {
+            iter: self.base.into_iter(),
+            update_op: self.update_op,
+        }
+    }
+}
+
+impl<I: AdaptiveIndexedIterator, F: Send + Sync + Clone> DivisibleAtIndex for Update<I, F> {}
+
+impl<I: AdaptiveIterator, F: Fn(&mut I::Item) + Send + Sync + Clone> AdaptiveIterator
+    for Update<I, F>
+{
+}
+impl<I: AdaptiveIndexedIterator, F: Fn(&mut I::Item) + Send + Sync + Clone> AdaptiveIndexedIterator
+    for Update<I, F>
+{
+}
@@ -4,15 +4,56 @@ use derive_divisible::{Divisible, DivisibleAtIndex, DivisibleIntoBlocks};
 use std;
 use std::iter;
 
+/// Two inputs of same length divided together.
+/// See `Zip::new`, `AdaptiveIndexedIterator::zip` and `DivisibleAtIndex::zip_divisible`.
 #[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
 #[derive(Divisible, DivisibleIntoBlocks, DivisibleAtIndex)]
 #[power(IndexedPower)]
-pub struct Zip<A: AdaptiveIterator, B: AdaptiveIterator> {
+pub struct Zip<A: DivisibleAtIndex, B: DivisibleAtIndex> {
     pub(crate) a: A,
     pub(crate) b: B,
 }
 
-impl<A: AdaptiveIterator, B: AdaptiveIterator> IntoIterator for Zip<A, B> {
+impl<A: DivisibleAtIndex, B: DivisibleAtIndex> Zip<A, B> {
+    /// Zip given inputs, which must have the same length.
+    /// This is typically used on mutable slices for in-place element-wise kernels.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// use rayon_adaptive::Zip;
+    /// let mut x = vec![1.0f64; 10_000];
+    /// let mut y = vec![2.0f64; 10_000];
+    /// // move 3x into y
+    /// Zip::new(x.as_mut_slice(), y.as_mut_slice())
+    ///     .into_adapt_iter()
+    ///     .for_each(|(x, y)| {
+    ///         *y += 3.0 * *x;
+    ///         *x = 0.0;
+    ///     });
+    /// assert!(x.iter().all(|&e| e == 0.0));
+    /// assert!(y.iter().all(|&e| e == 5.0));
+    /// ```
+    pub fn new(a: A, b: B) -> Self {
+        assert_eq!(
+            a.base_length(),
+            b.base_length(),
+            "zipped inputs must have the same length"
+        );
+        Zip { a, b }
+    }
+    /// Return both inputs.
+    pub fn into_inner(self) -> (A, B) {
+        (self.a, self.b)
+    }
+}
+
+impl<A, B> IntoIterator for Zip<A, B>
+where
+    A: DivisibleAtIndex + IntoIterator,
+    B: DivisibleAtIndex + IntoIterator,
+{
     type Item = (A::Item, B::Item);
     type IntoIter = iter::Zip<A::IntoIter, B::IntoIter>;
     fn into_iter(self) -> Self::IntoIter {
@@ -20,5 +61,5 @@ impl<A: AdaptiveIterator, B: AdaptiveIterator> IntoIterator for Zip<A, B> {
     }
 }
 
-impl<A: AdaptiveIterator, B: AdaptiveIterator> AdaptiveIterator for Zip<A, B> {}
+impl<A: AdaptiveIndexedIterator, B: AdaptiveIndexedIterator> AdaptiveIterator for Zip<A, B> {}
 impl<A: AdaptiveIndexedIterator, B: AdaptiveIndexedIterator> AdaptiveIndexedIterator for Zip<A, B> {}
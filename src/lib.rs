@@ -13,23 +13,86 @@ extern crate smallvec;
 use std::sync::atomic::{AtomicBool, Ordering};
 mod traits;
 pub use crate::traits::*;
+/// Derive `Divisible`, `DivisibleIntoBlocks` and `DivisibleAtIndex` for structs
+/// whose fields are all divisible inputs of equal lengths.
+/// All fields get divided at the same index.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::prelude::*;
+/// use rayon_adaptive::{Divisible, DivisibleAtIndex, DivisibleIntoBlocks, IndexedPower};
+///
+/// #[derive(Divisible, DivisibleIntoBlocks, DivisibleAtIndex)]
+/// #[power(IndexedPower)]
+/// struct Axpy<'a> {
+///     x: &'a [f64],
+///     y: &'a mut [f64],
+/// }
+///
+/// let x = vec![1.0; 1_000];
+/// let mut y = vec![1.0; 1_000];
+/// Axpy { x: &x, y: &mut y }.partial_for_each(|input, limit| {
+///     let (todo, remaining) = input.divide_at(limit);
+///     for (x, y) in todo.x.iter().zip(todo.y.iter_mut()) {
+///         *y += 2.0 * x
+///     }
+///     remaining
+/// });
+/// assert!(y.iter().all(|&e| e == 3.0));
+/// ```
+pub use derive_divisible::{Divisible, DivisibleAtIndex, DivisibleIntoBlocks};
 mod scheduling;
+pub use crate::scheduling::{set_deterministic, OrderedScheduler, Scheduler};
+mod chain;
+mod poison;
+pub use crate::chain::Then;
 pub mod utils;
 pub use crate::utils::fuse_slices;
 mod slices;
-pub use crate::slices::{EdibleSlice, EdibleSliceMut};
+pub use crate::slices::{
+    chunks_exact, chunks_exact_mut, overlapping_blocks, AdaptiveChunks, AdaptiveChunksMut,
+    AdaptiveSlice, AdaptiveWindows, ChunksExact, ChunksExactMut, EdibleSlice, EdibleSliceMut,
+    OverlappingBlocks,
+};
+mod owned;
+pub use crate::owned::OwnedSlice;
+mod btree;
+pub use crate::btree::BTreeRange;
+mod segments;
+pub use crate::segments::Segments;
+mod slice2d;
+pub use crate::slice2d::{Slice2D, Slice2DMut};
+mod concat;
+pub use crate::concat::Chain;
 mod activated_input;
+#[cfg(feature = "ndarray")]
+pub mod arrays;
+#[cfg(feature = "bitvec")]
+mod bits;
+pub mod instrumentation;
+#[cfg(feature = "mmap")]
+pub mod mmap;
+#[cfg(feature = "numa")]
+pub mod numa;
+pub mod producer;
+pub use crate::instrumentation::SchedulerHooks;
 mod chunks;
 pub mod iter;
 pub use crate::iter::hash::{par_elements, par_iter, par_keys};
 pub use crate::iter::iter::Iter;
 pub use crate::iter::map::Map;
 pub use crate::iter::zip::Zip;
+/// Output of `partition_map` closures.
+pub use itertools::Either;
 
 mod folders;
 pub use crate::folders::Folder;
 mod policy;
-pub use crate::policy::Policy;
+pub use crate::policy::{
+    environment_policy, set_default_policy, set_thread_default_policy, try_environment_policy,
+    AdaptiveParameters, ParsePolicyError, Policy,
+};
 mod atomiclist;
 pub mod prelude;
 mod smallchannel;
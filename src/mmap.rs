@@ -0,0 +1,127 @@
+//! Memory mapped files, divided as byte slices or at records boundaries.
+use crate::traits::BlockedPower;
+use crate::{Divisible, DivisibleIntoBlocks};
+use memmap2::Mmap;
+use std::cmp::min;
+use std::fs::File;
+use std::io;
+use std::path::Path;
+
+/// Read-only memory mapped file.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::mmap::MappedFile;
+/// use rayon_adaptive::prelude::*;
+/// use std::io::Write;
+/// let path = std::env::temp_dir().join("rayon_adaptive_mmap_example");
+/// let mut file = std::fs::File::create(&path).unwrap();
+/// for i in 0..10_000 {
+///     writeln!(file, "{}", i).unwrap();
+/// }
+/// let mapped = unsafe { MappedFile::open(&path) }.unwrap();
+/// // raw bytes
+/// let newlines = mapped
+///     .as_slice()
+///     .map_reduce(|b| b.iter().filter(|&&c| c == b'\n').count(), |a, b| a + b);
+/// assert_eq!(newlines, 10_000);
+/// // whole lines only
+/// let sum: u64 = mapped
+///     .records(|bytes: &[u8], index: usize| {
+///         bytes[index..]
+///             .iter()
+///             .position(|&c| c == b'\n')
+///             .map_or(bytes.len(), |p| index + p + 1)
+///     })
+///     .map_reduce(
+///         |lines| {
+///             std::str::from_utf8(lines.as_slice())
+///                 .unwrap()
+///                 .lines()
+///                 .map(|l| l.parse::<u64>().unwrap())
+///                 .sum()
+///         },
+///         |a, b| a + b,
+///     );
+/// assert_eq!(sum, 9_999 * 5_000);
+/// std::fs::remove_file(&path).unwrap();
+/// ```
+pub struct MappedFile {
+    map: Mmap,
+}
+
+impl MappedFile {
+    /// Map the file at given path in memory.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified (by us or by other processes) while mapped.
+    pub unsafe fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let file = File::open(path)?;
+        Ok(MappedFile {
+            map: Mmap::map(&file)?,
+        })
+    }
+    /// Return the file's content, which we can directly divide.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.map
+    }
+    /// Return the file's content, only divided at records boundaries.
+    /// `align(bytes, index)` returns the first boundary at or after `index` in `bytes`.
+    pub fn records<A>(&self, align: A) -> Records<'_, A>
+    where
+        A: Fn(&[u8], usize) -> usize + Send + Sync + Copy,
+    {
+        Records {
+            bytes: &self.map,
+            align,
+        }
+    }
+}
+
+/// Bytes only divided at records boundaries.
+/// See `MappedFile::records`.
+pub struct Records<'a, A> {
+    bytes: &'a [u8],
+    align: A,
+}
+
+impl<'a, A> Records<'a, A> {
+    /// Return our bytes (whole records only).
+    pub fn as_slice(&self) -> &'a [u8] {
+        self.bytes
+    }
+}
+
+impl<'a, A: Fn(&[u8], usize) -> usize + Send + Sync + Copy> Divisible for Records<'a, A> {
+    type Power = BlockedPower;
+    fn base_length(&self) -> usize {
+        self.bytes.len()
+    }
+    fn divide(self) -> (Self, Self) {
+        let mid = self.bytes.len() / 2;
+        self.divide_at(mid)
+    }
+}
+
+impl<'a, A: Fn(&[u8], usize) -> usize + Send + Sync + Copy> DivisibleIntoBlocks for Records<'a, A> {
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        let boundary_index = if index == 0 {
+            0
+        } else {
+            min((self.align)(self.bytes, index), self.bytes.len())
+        };
+        let (left, right) = self.bytes.split_at(boundary_index);
+        (
+            Records {
+                bytes: left,
+                align: self.align,
+            },
+            Records {
+                bytes: right,
+                align: self.align,
+            },
+        )
+    }
+}
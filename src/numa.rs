@@ -0,0 +1,104 @@
+//! NUMA hints: divide inputs spread on several memory nodes at nodes boundaries.
+use crate::prelude::*;
+use rayon::current_num_threads;
+#[cfg(not(feature = "logs"))]
+use rayon::current_thread_index;
+#[cfg(feature = "logs")]
+use real_rayon::current_thread_index;
+use std::cmp::min;
+
+/// Divisible whose content is spread evenly and contiguously on several NUMA nodes.
+/// Pieces spanning several nodes are always divided at nodes boundaries,
+/// such that each final piece lives on a single node.
+/// See `DivisibleIntoBlocks::with_numa_nodes`.
+pub struct NumaDivisible<I> {
+    pub(crate) real_content: I,
+    pub(crate) nodes: usize,
+    pub(crate) total_length: usize,
+    pub(crate) start: usize,
+}
+
+impl<I> NumaDivisible<I> {
+    // node holding element at given index
+    fn node_of(&self, index: usize) -> usize {
+        index * self.nodes / self.total_length
+    }
+    // index of first element on given node
+    fn node_start(&self, node: usize) -> usize {
+        (node * self.total_length).div_ceil(self.nodes)
+    }
+}
+
+impl<I: DivisibleIntoBlocks> NumaDivisible<I> {
+    /// Return the node holding the start of our content.
+    pub fn node(&self) -> usize {
+        if self.total_length == 0 {
+            0
+        } else {
+            self.node_of(min(self.start, self.total_length - 1))
+        }
+    }
+    /// Return if the current thread lives on the node holding our content,
+    /// assuming the pool's threads are pinned on the nodes in order.
+    pub fn is_local(&self) -> bool {
+        let threads = current_num_threads();
+        match current_thread_index() {
+            Some(thread) => thread * self.nodes / threads == self.node(),
+            None => true,
+        }
+    }
+}
+
+impl<I: DivisibleIntoBlocks> Divisible for NumaDivisible<I> {
+    type Power = I::Power;
+    fn base_length(&self) -> usize {
+        self.real_content.base_length()
+    }
+    fn divide(self) -> (Self, Self) {
+        let length = self.base_length();
+        if length < 2 {
+            return self.divide_at(length / 2);
+        }
+        let first_node = self.node_of(self.start);
+        let last_node = self.node_of(self.start + length - 1);
+        if first_node == last_node {
+            self.divide_at(length / 2)
+        } else {
+            let middle_node = (first_node + last_node).div_ceil(2);
+            let boundary = self.node_start(middle_node) - self.start;
+            self.divide_at(boundary)
+        }
+    }
+}
+
+impl<I: DivisibleIntoBlocks> DivisibleIntoBlocks for NumaDivisible<I> {
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.real_content.divide_at(index);
+        (
+            NumaDivisible {
+                real_content: left,
+                nodes: self.nodes,
+                total_length: self.total_length,
+                start: self.start,
+            },
+            NumaDivisible {
+                real_content: right,
+                nodes: self.nodes,
+                total_length: self.total_length,
+                start: self.start + index,
+            },
+        )
+    }
+}
+
+impl<I: DivisibleAtIndex> DivisibleAtIndex for NumaDivisible<I> {}
+
+impl<I: AdaptiveIterator> AdaptiveIterator for NumaDivisible<I> {}
+
+impl<I: IntoIterator> IntoIterator for NumaDivisible<I> {
+    type IntoIter = I::IntoIter;
+    type Item = I::Item;
+    fn into_iter(self) -> Self::IntoIter {
+        self.real_content.into_iter()
+    }
+}
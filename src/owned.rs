@@ -0,0 +1,163 @@
+//! Owned slices: take ownership of a vector's content and divide it.
+use crate::traits::IndexedPower;
+use crate::{Divisible, DivisibleAtIndex, DivisibleIntoBlocks};
+use std::mem::ManuallyDrop;
+use std::ptr;
+use std::slice;
+use std::sync::Arc;
+
+// memory of the initial vector, freed (without dropping any element)
+// once all pieces are gone.
+struct Buffer<T> {
+    pointer: *mut T,
+    capacity: usize,
+}
+
+unsafe impl<T: Send> Send for Buffer<T> {}
+unsafe impl<T: Send> Sync for Buffer<T> {}
+
+impl<T> Drop for Buffer<T> {
+    fn drop(&mut self) {
+        unsafe { Vec::from_raw_parts(self.pointer, 0, self.capacity) };
+    }
+}
+
+/// Part of a vector we own.
+/// Dividing splits the ownership of the elements without moving them
+/// and iterating moves out the elements.
+/// Elements which are not consumed get dropped with the slice.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::prelude::*;
+/// use rayon_adaptive::OwnedSlice;
+/// let strings: Vec<String> = (0..1_000).map(|i| i.to_string()).collect();
+/// let total_length: usize = OwnedSlice::from(strings)
+///     .into_adapt_iter()
+///     .map(|s: String| s.len())
+///     .sum();
+/// assert_eq!(total_length, 10 + 90 * 2 + 900 * 3);
+/// ```
+pub struct OwnedSlice<T> {
+    buffer: Arc<Buffer<T>>,
+    start: usize,
+    end: usize,
+}
+
+unsafe impl<T: Send> Send for OwnedSlice<T> {}
+unsafe impl<T: Sync> Sync for OwnedSlice<T> {}
+
+impl<T> From<Vec<T>> for OwnedSlice<T> {
+    fn from(vector: Vec<T>) -> Self {
+        let mut vector = ManuallyDrop::new(vector);
+        let end = vector.len();
+        OwnedSlice {
+            buffer: Arc::new(Buffer {
+                pointer: vector.as_mut_ptr(),
+                capacity: vector.capacity(),
+            }),
+            start: 0,
+            end,
+        }
+    }
+}
+
+impl<T> OwnedSlice<T> {
+    /// Return the elements we own.
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.buffer.pointer.add(self.start), self.end - self.start) }
+    }
+    /// Return the elements we own, mutably.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe {
+            slice::from_raw_parts_mut(self.buffer.pointer.add(self.start), self.end - self.start)
+        }
+    }
+    // take back the buffer, forgetting about our elements
+    fn into_buffer(self) -> (Arc<Buffer<T>>, usize, usize) {
+        let owned = ManuallyDrop::new(self);
+        (unsafe { ptr::read(&owned.buffer) }, owned.start, owned.end)
+    }
+}
+
+impl<T> Drop for OwnedSlice<T> {
+    fn drop(&mut self) {
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) }
+    }
+}
+
+impl<T: Send + Sync> Divisible for OwnedSlice<T> {
+    type Power = IndexedPower;
+    fn base_length(&self) -> usize {
+        self.end - self.start
+    }
+    fn divide(self) -> (Self, Self) {
+        let mid = self.base_length() / 2;
+        self.divide_at(mid)
+    }
+}
+
+impl<T: Send + Sync> DivisibleIntoBlocks for OwnedSlice<T> {
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        assert!(index <= self.base_length());
+        let (buffer, start, end) = self.into_buffer();
+        (
+            OwnedSlice {
+                buffer: buffer.clone(),
+                start,
+                end: start + index,
+            },
+            OwnedSlice {
+                buffer,
+                start: start + index,
+                end,
+            },
+        )
+    }
+}
+
+impl<T: Send + Sync> DivisibleAtIndex for OwnedSlice<T> {}
+
+/// Iterator moving out the elements of an `OwnedSlice`.
+pub struct IntoIter<T> {
+    slice: OwnedSlice<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+    fn next(&mut self) -> Option<T> {
+        if self.slice.start == self.slice.end {
+            None
+        } else {
+            let element = unsafe { ptr::read(self.slice.buffer.pointer.add(self.slice.start)) };
+            self.slice.start += 1;
+            Some(element)
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.slice.end - self.slice.start;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.slice.start == self.slice.end {
+            None
+        } else {
+            self.slice.end -= 1;
+            Some(unsafe { ptr::read(self.slice.buffer.pointer.add(self.slice.end)) })
+        }
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for OwnedSlice<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { slice: self }
+    }
+}
@@ -0,0 +1,165 @@
+//! Panic safety: the first panic poisons all remaining work and is then
+//! re-raised on the calling thread.
+use crate::folders::Folder;
+use crate::traits::{Divisible, DivisibleAtIndex, DivisibleIntoBlocks};
+use std::any::Any;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+/// Record of the first panic raised by user code during a computation.
+pub(crate) struct PanicSlot {
+    poisoned: AtomicBool,
+    payload: Mutex<Option<Box<dyn Any + Send>>>,
+}
+
+impl PanicSlot {
+    pub(crate) fn new() -> Self {
+        PanicSlot {
+            poisoned: AtomicBool::new(false),
+            payload: Mutex::new(None),
+        }
+    }
+    pub(crate) fn is_poisoned(&self) -> bool {
+        self.poisoned.load(Ordering::Relaxed)
+    }
+    /// Run given closure, returning `None` if it panics (or if we are already poisoned).
+    pub(crate) fn catch<R, C: FnOnce() -> R>(&self, closure: C) -> Option<R> {
+        if self.is_poisoned() {
+            return None;
+        }
+        catch_unwind(AssertUnwindSafe(closure))
+            .map_err(|payload| {
+                let mut first_payload = self.payload.lock().unwrap_or_else(|e| e.into_inner());
+                if first_payload.is_none() {
+                    *first_payload = Some(payload);
+                }
+                self.poisoned.store(true, Ordering::Relaxed);
+            })
+            .ok()
+    }
+    /// Return final result, re-raising the first panic if any.
+    pub(crate) fn into_result<R>(self, result: Option<R>) -> R {
+        let payload = self.payload.into_inner().unwrap_or_else(|e| e.into_inner());
+        if let Some(payload) = payload {
+            resume_unwind(payload)
+        }
+        result.expect("lost result without panicking")
+    }
+}
+
+/// Input which looks empty once poisoned.
+/// Its content is lost if the user code folding it panics.
+pub(crate) struct Poisonable<'a, I> {
+    pub(crate) input: Option<I>,
+    pub(crate) slot: &'a PanicSlot,
+}
+
+impl<'a, I> Poisonable<'a, I> {
+    pub(crate) fn new(input: I, slot: &'a PanicSlot) -> Self {
+        Poisonable {
+            input: Some(input),
+            slot,
+        }
+    }
+    fn empty(slot: &'a PanicSlot) -> Self {
+        Poisonable { input: None, slot }
+    }
+}
+
+impl<'a, I: Divisible> Divisible for Poisonable<'a, I> {
+    type Power = I::Power;
+    fn base_length(&self) -> usize {
+        if self.slot.is_poisoned() {
+            0
+        } else {
+            self.input.as_ref().map_or(0, |i| i.base_length())
+        }
+    }
+    fn divide(self) -> (Self, Self) {
+        match self.input {
+            Some(input) => {
+                let (left, right) = input.divide();
+                (
+                    Poisonable::new(left, self.slot),
+                    Poisonable::new(right, self.slot),
+                )
+            }
+            None => (Poisonable::empty(self.slot), Poisonable::empty(self.slot)),
+        }
+    }
+}
+
+impl<'a, I: DivisibleIntoBlocks> DivisibleIntoBlocks for Poisonable<'a, I> {
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        match self.input {
+            Some(input) => {
+                let (left, right) = input.divide_at(index);
+                (
+                    Poisonable::new(left, self.slot),
+                    Poisonable::new(right, self.slot),
+                )
+            }
+            None => (Poisonable::empty(self.slot), Poisonable::empty(self.slot)),
+        }
+    }
+}
+
+impl<'a, I: DivisibleAtIndex> DivisibleAtIndex for Poisonable<'a, I> {}
+
+/// Folder catching all panics of the inner folder.
+/// Outputs are `None` when some user code panicked.
+pub(crate) struct PanicSafeFolder<'a, F> {
+    pub(crate) inner_folder: &'a F,
+    pub(crate) slot: &'a PanicSlot,
+}
+
+impl<'a, F: Folder> Folder for PanicSafeFolder<'a, F> {
+    type Input = Poisonable<'a, F::Input>;
+    type IntermediateOutput = Option<F::IntermediateOutput>;
+    type Output = Option<F::Output>;
+    fn identity(&self) -> Self::IntermediateOutput {
+        self.slot.catch(|| self.inner_folder.identity())
+    }
+    fn fold(
+        &self,
+        io: Self::IntermediateOutput,
+        i: Self::Input,
+        limit: usize,
+    ) -> (Self::IntermediateOutput, Self::Input) {
+        match (io, i.input) {
+            (Some(io), Some(input)) => {
+                match self.slot.catch(|| self.inner_folder.fold(io, input, limit)) {
+                    Some((io, input)) => (Some(io), Poisonable::new(input, self.slot)),
+                    None => (None, Poisonable::empty(self.slot)),
+                }
+            }
+            (_, input) => (
+                None,
+                Poisonable {
+                    input,
+                    slot: self.slot,
+                },
+            ),
+        }
+    }
+    fn to_output(&self, io: Self::IntermediateOutput, i: Self::Input) -> Self::Output {
+        match (io, i.input) {
+            (Some(io), Some(input)) => self.slot.catch(|| self.inner_folder.to_output(io, input)),
+            _ => None,
+        }
+    }
+}
+
+/// Reduce two outputs, poisoning everything if the reduction panics.
+pub(crate) fn reduce_poisonable<O, RF: Fn(O, O) -> O>(
+    slot: &PanicSlot,
+    reduce_function: &RF,
+    left: Option<O>,
+    right: Option<O>,
+) -> Option<O> {
+    match (left, right) {
+        (Some(left), Some(right)) => slot.catch(|| reduce_function(left, right)),
+        _ => None,
+    }
+}
@@ -1,37 +1,351 @@
 use crate::activated_input::ActivatedInput;
+use crate::chain::Then;
 /// All scheduling available scheduling policies.
 use crate::folders::{cutting_fold::CuttingFold, fold::Fold, work_fold::WorkFold, Folder};
-use crate::scheduling::schedule;
-use crate::traits::{BasicPower, BlockedOrMore};
+use crate::scheduling::{schedule, Scheduler};
+use crate::traits::{divide_checked, BasicPower, BlockedOrMore};
+use crate::utils::{powers, AbortingDivisible};
 use crate::{Divisible, DivisibleIntoBlocks};
+use std::cell::Cell;
+use std::cmp::{max, min};
+use std::error::Error;
+use std::fmt;
 use std::iter::{empty, once, Empty};
 use std::marker::PhantomData;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{OnceLock, RwLock};
+use std::time::{Duration, Instant};
 
+/// Scheduling policies available for any `Divisible` input.
+///
+/// A policy is selected with `with_policy`.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::prelude::*;
+/// use rayon_adaptive::Policy;
+/// // work sequentially on blocks of sizes growing from 10 up to 1000
+/// // and only split when another thread tries to steal.
+/// let max = (0..10_000)
+///     .into_adapt_iter()
+///     .with_policy(Policy::Adaptive(10, 1000))
+///     .max();
+/// assert_eq!(max, Some(9_999));
+/// ```
 #[derive(Copy, Clone)]
 pub enum Policy {
     /// Adaptive scheduling policy with dynamic block sizes.
     DefaultPolicy,
-    /// Do all computations sequentially.
+    /// Do all computations sequentially, on the calling thread.
     Sequential,
     /// Recursively cut in two with join until given block size.
     Join(usize),
+    /// Recursively cut in two with join until given depth.
+    /// We never create more than 2**depth sequential tasks.
+    JoinDepth(usize),
     /// Recursively cut in two with join_context until given block size.
+    /// Right halves are only divided further if they get stolen,
+    /// otherwise they are processed sequentially.
     JoinContext(usize),
     /// Recursively cut in two with depjoin until given block size.
+    /// Halves are reduced by the last of the two tasks to finish, as soon as it is done,
+    /// which suits reductions depending on the left half like prefix computations.
     DepJoin(usize),
+    /// Recursively cut in two until given block size, spawning both halves
+    /// as tasks (help first) instead of working on the left one (work first).
+    /// The current thread helps executing tasks until both halves are done.
+    HelpFirst(usize),
     /// Advance locally with increasing block sizes. When stolen create tasks
     /// We need an initial block size and a maximal block size.
     Adaptive(usize, usize),
+    /// Like `Adaptive` but with all settings of the adaptive scheduler exposed.
+    AdaptiveWith(AdaptiveParameters),
     /// Mirrors the rayon join context.
     Rayon,
+    /// Like `Adaptive` but the initial block size is computed at runtime
+    /// by timing the first sequential blocks.
+    AutoTuned,
+}
+
+/// Settings for the adaptive scheduler.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::prelude::*;
+/// use rayon_adaptive::{AdaptiveParameters, Policy};
+/// // blocks sizes start at 100 and grow by 50% each time up to 10_000
+/// let parameters = AdaptiveParameters::new(100, 10_000).growth(1.5);
+/// let s: usize = (0..100_000)
+///     .into_adapt_iter()
+///     .with_policy(Policy::AdaptiveWith(parameters))
+///     .sum();
+/// assert_eq!(s, 99_999 * 50_000);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct AdaptiveParameters {
+    pub(crate) init_size: usize,
+    pub(crate) max_size: usize,
+    pub(crate) growth: f64,
+    pub(crate) max_threads: usize,
+    pub(crate) sequential_threshold: usize,
+    pub(crate) max_block_duration: Option<Duration>,
+}
+
+impl Policy {
+    /// Schedule with ourselves but hand pieces of input of at most `length` elements
+    /// over to the `next` scheduler.
+    /// Pieces are handed over when we start working on them, so for adaptive
+    /// policies this only concerns stolen pieces.
+    /// Chains are built by nesting: `a.then(n, b.then(m, c))`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// use rayon_adaptive::Policy;
+    /// // join down to depth 3 then go on adaptively.
+    /// let scheduler = Policy::JoinDepth(3).then(usize::MAX, Policy::Adaptive(100, 1000));
+    /// let s = (0..100_000u64)
+    ///     .into_adapt_iter()
+    ///     .fold(|| 0, |s, e| s + e)
+    ///     .reduce_with(&scheduler, |a, b| a + b);
+    /// assert_eq!(s, 99_999 * 50_000);
+    /// ```
+    pub fn then<S: Scheduler>(self, length: usize, next: S) -> Then<Policy, S> {
+        Then {
+            first: self,
+            length,
+            next,
+        }
+    }
+}
+
+impl AdaptiveParameters {
+    /// Sequential blocks start at `init_size` and double up to `max_size`.
+    pub fn new(init_size: usize, max_size: usize) -> Self {
+        AdaptiveParameters {
+            init_size,
+            max_size,
+            growth: 2.0,
+            max_threads: usize::MAX,
+            sequential_threshold: 0,
+            max_block_duration: None,
+        }
+    }
+    /// Multiply blocks sizes by given factor after each block
+    /// (until reaching the maximal size).
+    /// Factor needs to be strictly greater than 1.
+    pub fn growth(self, growth: f64) -> Self {
+        assert!(growth > 1.0, "blocks sizes need to grow");
+        AdaptiveParameters { growth, ..self }
+    }
+    /// Never let more than given number of threads work on the computation
+    /// at the same time, whatever the pool size.
+    /// This is not taken into account by helping folds.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// use rayon_adaptive::{AdaptiveParameters, Policy};
+    /// let parameters = AdaptiveParameters::new(100, 10_000).max_threads(2);
+    /// let s: usize = (0..100_000)
+    ///     .into_adapt_iter()
+    ///     .with_policy(Policy::AdaptiveWith(parameters))
+    ///     .sum();
+    /// assert_eq!(s, 99_999 * 50_000);
+    /// ```
+    pub fn max_threads(self, max_threads: usize) -> Self {
+        assert!(max_threads > 0, "we need at least one thread");
+        AdaptiveParameters {
+            max_threads,
+            ..self
+        }
+    }
+    /// Stop answering steal requests once remaining input length drops to given threshold:
+    /// small remainders get finished sequentially.
+    pub fn sequential_threshold(self, sequential_threshold: usize) -> Self {
+        AdaptiveParameters {
+            sequential_threshold,
+            ..self
+        }
+    }
+    /// Bound the duration of each sequential block.
+    /// Blocks sizes get reduced when needed by timing previous blocks.
+    /// This enables the pool to stay responsive for other tasks.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// use rayon_adaptive::{AdaptiveParameters, Policy};
+    /// use std::time::{Duration, Instant};
+    /// let parameters =
+    ///     AdaptiveParameters::new(100, 10_000).max_block_duration(Duration::from_micros(100));
+    /// let s: usize = (0..100_000)
+    ///     .into_adapt_iter()
+    ///     .with_policy(Policy::AdaptiveWith(parameters))
+    ///     .sum();
+    /// assert_eq!(s, 99_999 * 50_000);
+    /// ```
+    pub fn max_block_duration(self, max_block_duration: Duration) -> Self {
+        AdaptiveParameters {
+            max_block_duration: Some(max_block_duration),
+            ..self
+        }
+    }
+}
+
+// policy used when none is given, for all threads
+static DEFAULT_POLICY: RwLock<Policy> = RwLock::new(Policy::DefaultPolicy);
+
+// policy used when none is given, for the current thread
+thread_local!(static THREAD_DEFAULT_POLICY: Cell<Option<Policy>> = Cell::new(None));
+
+/// Set the policy used by all runs which are not given one with `with_policy`.
+pub fn set_default_policy(policy: Policy) {
+    *DEFAULT_POLICY
+        .write()
+        .expect("default policy lock poisoned") = policy;
+}
+
+/// Set the policy used by runs started on the current thread
+/// which are not given one with `with_policy`.
+/// This takes precedence over `set_default_policy`.
+///
+/// Calling it in a thread pool's start handler
+/// sets the default policy of the whole pool.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::prelude::*;
+/// use rayon_adaptive::{set_thread_default_policy, Policy};
+/// let pool = rayon::ThreadPoolBuilder::new()
+///     .start_handler(|_| set_thread_default_policy(Policy::Join(100)))
+///     .build()
+///     .expect("pool creation failed");
+/// let s: usize = pool.install(|| (0..10_000).into_adapt_iter().sum());
+/// assert_eq!(s, 9_999 * 5_000);
+/// ```
+pub fn set_thread_default_policy(policy: Policy) {
+    THREAD_DEFAULT_POLICY.with(|p| p.set(Some(policy)))
 }
 
 impl Default for Policy {
+    /// Return the current thread's default policy if any, else the global one.
     fn default() -> Self {
-        Policy::DefaultPolicy
+        THREAD_DEFAULT_POLICY
+            .with(|p| p.get())
+            .unwrap_or_else(|| *DEFAULT_POLICY.read().expect("default policy lock poisoned"))
+    }
+}
+
+/// Error returned when parsing an invalid policy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsePolicyError(String);
+
+impl fmt::Display for ParsePolicyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid policy: {}", self.0)
     }
 }
 
+impl Error for ParsePolicyError {}
+
+impl FromStr for Policy {
+    type Err = ParsePolicyError;
+    /// Parse a policy name followed by its parameters, all separated by ':'.
+    /// For example "sequential", "join:1000" or "adaptive:100:10000".
+    /// Adaptive policies accept optional extra parameters:
+    /// "adaptive:init_size:max_size:growth:sequential_threshold:max_threads".
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::Policy;
+    /// let policy: Policy = "adaptive:100:10000".parse().expect("invalid policy");
+    /// let policy: Policy = "adaptive:100:10000:1.5:50".parse().expect("invalid policy");
+    /// assert!("join:many".parse::<Policy>().is_err());
+    /// assert!("adaptive:100:10000:0.5".parse::<Policy>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ParsePolicyError(s.to_string());
+        let mut fields = s.trim().split(':');
+        let name = fields.next().ok_or_else(error)?.to_lowercase();
+        let fields: Vec<&str> = fields.map(|field| field.trim()).collect();
+        let size = |field: &str| field.parse::<usize>().map_err(|_| error());
+        match (name.as_str(), fields.as_slice()) {
+            ("default", []) => Ok(Policy::DefaultPolicy),
+            ("sequential", []) => Ok(Policy::Sequential),
+            ("rayon", []) => Ok(Policy::Rayon),
+            ("autotuned", []) => Ok(Policy::AutoTuned),
+            ("join", [block_size]) => Ok(Policy::Join(size(block_size)?)),
+            ("join_depth", [depth]) => Ok(Policy::JoinDepth(size(depth)?)),
+            ("join_context", [block_size]) => Ok(Policy::JoinContext(size(block_size)?)),
+            ("depjoin", [block_size]) => Ok(Policy::DepJoin(size(block_size)?)),
+            ("help_first", [block_size]) => Ok(Policy::HelpFirst(size(block_size)?)),
+            ("adaptive", [init_size, max_size]) => {
+                Ok(Policy::Adaptive(size(init_size)?, size(max_size)?))
+            }
+            ("adaptive", [init_size, max_size, growth, others @ ..]) if others.len() <= 2 => {
+                let growth = growth.parse::<f64>().map_err(|_| error())?;
+                if growth.is_nan() || growth <= 1.0 {
+                    return Err(error());
+                }
+                let mut parameters =
+                    AdaptiveParameters::new(size(init_size)?, size(max_size)?).growth(growth);
+                if let Some(threshold) = others.first() {
+                    parameters = parameters.sequential_threshold(size(threshold)?);
+                }
+                if let Some(max_threads) = others.get(1) {
+                    let max_threads = size(max_threads)?;
+                    if max_threads == 0 {
+                        return Err(error());
+                    }
+                    parameters = parameters.max_threads(max_threads);
+                }
+                Ok(Policy::AdaptiveWith(parameters))
+            }
+            _ => Err(error()),
+        }
+    }
+}
+
+/// Return the policy given by the `RAYON_ADAPTIVE_POLICY` environment variable (if any),
+/// or the error obtained when parsing it.
+/// The variable is only read once.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::try_environment_policy;
+/// if let Some(Err(error)) = try_environment_policy() {
+///     println!("RAYON_ADAPTIVE_POLICY is ignored: {}", error);
+/// }
+/// ```
+pub fn try_environment_policy() -> Option<Result<Policy, ParsePolicyError>> {
+    static ENVIRONMENT_POLICY: OnceLock<Option<Result<Policy, ParsePolicyError>>> = OnceLock::new();
+    ENVIRONMENT_POLICY
+        .get_or_init(|| {
+            std::env::var("RAYON_ADAPTIVE_POLICY")
+                .ok()
+                .map(|variable| variable.parse())
+        })
+        .clone()
+}
+
+/// Return the policy given by the `RAYON_ADAPTIVE_POLICY` environment variable (if any).
+/// Once set, this policy overrides all policies given in the code,
+/// which is useful for experiments.
+/// Invalid values are ignored, see `try_environment_policy` to detect them.
+pub fn environment_policy() -> Option<Policy> {
+    try_environment_policy().and_then(Result::ok)
+}
+
 /// We can assign a scheduling policy to any `Divisible input`.
 /// We obtain this structure holding policy and input together.
 pub struct ParametrizedInput<I: Divisible, S: Iterator<Item = usize>> {
@@ -40,6 +354,136 @@ pub struct ParametrizedInput<I: Divisible, S: Iterator<Item = usize>> {
     pub(crate) sizes: S,
 }
 
+impl<I: Divisible, S: Iterator<Item = usize>> ParametrizedInput<I, S> {
+    /// Limit division depth so that we never create more than 2**depth leaves.
+    /// Policies with a block size get their block size increased if needed,
+    /// default, rayon and adaptive policies get replaced by a depth bounded one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// use rayon_adaptive::Policy;
+    /// // no more than 8 tasks
+    /// let s: usize = (0..100_000)
+    ///     .into_adapt_iter()
+    ///     .with_policy(Policy::Join(10))
+    ///     .with_max_depth(3)
+    ///     .sum();
+    /// assert_eq!(s, 99_999 * 50_000);
+    /// let leaves = (0..100_000)
+    ///     .with_policy(Policy::Adaptive(10, 100))
+    ///     .with_max_depth(3)
+    ///     .map_reduce(|_| 1, |a, b| a + b);
+    /// assert!(leaves <= 8);
+    /// ```
+    pub fn with_max_depth(self, depth: usize) -> Self {
+        let length = self.input.base_length();
+        let leaves = 2usize.checked_pow(depth as u32).unwrap_or(usize::MAX);
+        let leaf_size = length.div_ceil(leaves);
+        let policy = match self.policy {
+            Policy::Sequential => Policy::Sequential,
+            Policy::DefaultPolicy | Policy::AutoTuned => Policy::JoinContext(leaf_size),
+            Policy::Rayon => Policy::JoinDepth(depth),
+            Policy::JoinDepth(d) => Policy::JoinDepth(min(d, depth)),
+            Policy::Join(b) => Policy::Join(max(b, leaf_size)),
+            Policy::JoinContext(b) => Policy::JoinContext(max(b, leaf_size)),
+            Policy::DepJoin(b) => Policy::DepJoin(max(b, leaf_size)),
+            Policy::HelpFirst(b) => Policy::HelpFirst(max(b, leaf_size)),
+            // adaptive schedulers divide on each steal whatever the depth
+            Policy::Adaptive(..) | Policy::AdaptiveWith(_) => Policy::JoinDepth(depth),
+        };
+        ParametrizedInput { policy, ..self }
+    }
+}
+
+impl<I: DivisibleIntoBlocks, S: Iterator<Item = usize>> ParametrizedInput<I, S> {
+    /// Fold step by step (like `partial_fold`) macro blocks until given deadline passes.
+    /// The deadline is checked before starting each macro block (see `by_blocks`).
+    /// Return the reduced output of all processed blocks and the remaining input.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// use rayon_adaptive::Policy;
+    /// use std::ops::Range;
+    /// use std::time::{Duration, Instant};
+    /// let sum = |s: u64, r: Range<u64>, limit: usize| {
+    ///     let (todo, remaining) = r.divide_at(limit);
+    ///     (s + todo.sum::<u64>(), remaining)
+    /// };
+    /// let deadline = Instant::now() + Duration::from_secs(60);
+    /// let (s, remaining) = (0..10_000u64)
+    ///     .with_policy(Policy::DefaultPolicy)
+    ///     .run_until(deadline, || 0, sum, |a, b| a + b);
+    /// assert_eq!(s, 9_999 * 5_000);
+    /// assert_eq!(remaining.base_length(), 0);
+    /// // deadline is already over, we do nothing
+    /// let (s, remaining) = (0..10_000u64)
+    ///     .with_policy(Policy::DefaultPolicy)
+    ///     .run_until(Instant::now(), || 0, sum, |a, b| a + b);
+    /// assert_eq!(s, 0);
+    /// assert_eq!(remaining.base_length(), 10_000);
+    /// ```
+    pub fn run_until<O, ID, F, RF>(
+        self,
+        deadline: Instant,
+        identity: ID,
+        fold_op: F,
+        reduce_function: RF,
+    ) -> (O, I)
+    where
+        O: Send + Sync,
+        ID: Fn() -> O + Sync,
+        F: Fn(O, I, usize) -> (O, I) + Sync,
+        RF: Fn(O, O) -> O + Sync,
+    {
+        let policy = self.policy;
+        self.run_until_with(&policy, deadline, identity, fold_op, reduce_function)
+    }
+
+    /// Like `run_until` but scheduling each macro block with given scheduler
+    /// instead of our policy.
+    pub fn run_until_with<SC, O, ID, F, RF>(
+        self,
+        scheduler: &SC,
+        deadline: Instant,
+        identity: ID,
+        fold_op: F,
+        reduce_function: RF,
+    ) -> (O, I)
+    where
+        SC: Scheduler,
+        O: Send + Sync,
+        ID: Fn() -> O + Sync,
+        F: Fn(O, I, usize) -> (O, I) + Sync,
+        RF: Fn(O, O) -> O + Sync,
+    {
+        let folder = Fold {
+            identity_op: identity,
+            fold_op,
+            phantom: PhantomData,
+        };
+        let mut input = self.input;
+        let length = input.base_length();
+        let base_size = max((length as f64).log(2.0).ceil() as usize, 1);
+        let mut sizes = self.sizes.chain(powers(base_size));
+        let mut output = folder.identity();
+        while input.base_length() > 0 && Instant::now() < deadline {
+            let size = min(
+                sizes.next().expect("not enough sizes for chunks"),
+                input.base_length(),
+            );
+            let (block, remaining_input) = divide_checked(input, size);
+            input = remaining_input;
+            let block_output = scheduler.schedule(block, &folder, &reduce_function);
+            output = reduce_function(output, block_output);
+        }
+        (output, input)
+    }
+}
+
 /********************************************************************************/
 /*                          Runner Traits definitions                           */
 /********************************************************************************/
@@ -74,6 +518,40 @@ pub trait AllAdaptiveRunner<I: Divisible, S: Iterator<Item = usize>, P>:
             power: PhantomData,
         }
     }
+    /// Fold step by step.
+    /// `fold_op(accumulator, input, limit)` processes at most `limit` elements
+    /// at the beginning of `input` and returns the updated accumulator
+    /// together with the input left untouched.
+    /// The scheduler checks for steals between two calls: stolen parts start again
+    /// from `identity()` and all outputs then need to be reduced.
+    /// This is the building block for adaptive algorithms on iterators which
+    /// need to see their input (like prefix computations).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// // count elements greater than all previous ones in their block
+    /// let v: Vec<u32> = (0..100_000).collect();
+    /// let (count, max) = v
+    ///     .as_slice()
+    ///     .into_adapt_iter()
+    ///     .partial_fold(
+    ///         || (0, None),
+    ///         |(mut count, mut max), input, limit| {
+    ///             let (todo, remaining) = input.divide_at(limit);
+    ///             for &e in todo {
+    ///                 if max.map(|m| e > m).unwrap_or(true) {
+    ///                     count += 1;
+    ///                     max = Some(e);
+    ///                 }
+    ///             }
+    ///             ((count, max), remaining)
+    ///         },
+    ///     )
+    ///     .reduce(|(c1, m1), (c2, m2)| (c1 + c2, std::cmp::max(m1, m2)));
+    /// assert_eq!((count, max), (100_000, Some(99_999)));
+    /// ```
     fn partial_fold<O, ID, F>(
         self,
         identity: ID,
@@ -110,6 +588,20 @@ pub trait AllAdaptiveRunner<I: Divisible, S: Iterator<Item = usize>, P>:
 pub trait BlockAdaptiveRunner<I: DivisibleIntoBlocks, S: Iterator<Item = usize>>:
     AdaptiveRunner<I, S>
 {
+    /// Like `partial_fold` but the scheduler cuts the blocks itself:
+    /// `fold_op` gets the accumulator and the next block to process entirely.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let blocks_sums: Vec<u64> = (0..10_000u64)
+    ///     .into_adapt_iter()
+    ///     .cutting_fold(|| 0, |s, block| s + block.into_iter().sum::<u64>())
+    ///     .into_iter()
+    ///     .collect();
+    /// assert_eq!(blocks_sums.iter().sum::<u64>(), 9_999 * 5_000);
+    /// ```
     fn cutting_fold<O, ID, F>(
         self,
         identity: ID,
@@ -136,6 +628,22 @@ pub trait BlockAdaptiveRunner<I: DivisibleIntoBlocks, S: Iterator<Item = usize>>
     }
 
     /// Replace block sizes iterator (if any) by given one.
+    /// Input is then processed in successive macro blocks of given sizes.
+    /// Each block is processed in parallel but blocks are processed in order,
+    /// which is useful for memory bound or early exit algorithms.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// use rayon_adaptive::utils::powers;
+    /// // macro blocks of sizes 200, 400, 800, ...
+    /// let found = (0..100_000)
+    ///     .into_adapt_iter()
+    ///     .by_blocks(powers(100))
+    ///     .find_first(|&x| x % 1000 == 999);
+    /// assert_eq!(found, Some(999));
+    /// ```
     fn by_blocks<S2: Iterator<Item = usize>>(self, sizes: S2) -> ParametrizedInput<I, S2> {
         let (input, policy, _) = self.input_policy_sizes();
         ParametrizedInput {
@@ -156,7 +664,7 @@ pub trait BlockAdaptiveRunner<I: DivisibleIntoBlocks, S: Iterator<Item = usize>>
         let folder = Fold {
             identity_op: || None,
             fold_op: |o: Option<O>, i: I, limit: usize| -> (Option<O>, I) {
-                let (todo_now, remaining) = i.divide_at(limit);
+                let (todo_now, remaining) = divide_checked(i, limit);
                 let new_result = map_function(todo_now);
                 (
                     if let Some(output) = o {
@@ -186,6 +694,100 @@ pub trait BlockAdaptiveRunner<I: DivisibleIntoBlocks, S: Iterator<Item = usize>>
         let first_output = outputs.next().unwrap();
         outputs.fold(first_output, reduce_reference)
     }
+
+    /// Like `map_reduce` but closures may fail.
+    /// On the first error all remaining blocks are skipped and an error is returned.
+    /// Empty inputs return `Ok(identity())`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let v: Vec<u64> = (0..10_000).collect();
+    /// let checked_sum = |s: &[u64]| {
+    ///     s.iter()
+    ///         .try_fold(0u64, |acc, &e| if e == 5_000 { Err(e) } else { Ok(acc + e) })
+    /// };
+    /// let add = |a, b| Ok(a + b);
+    /// assert_eq!(v[..5_000].try_run(|| 0, checked_sum, add), Ok(4_999 * 2_500));
+    /// assert_eq!(v.as_slice().try_run(|| 0, checked_sum, add), Err(5_000));
+    /// assert_eq!(v[..0].try_run(|| 0, checked_sum, add), Ok(0));
+    /// ```
+    ///
+    /// Errors early in large inputs stop the computations:
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// let processed = AtomicUsize::new(0);
+    /// let result = (0..100_000_000u64)
+    ///     .by_blocks(std::iter::repeat(100_000))
+    ///     .try_run(
+    ///         || 0,
+    ///         |r| {
+    ///             processed.fetch_add(r.base_length(), Ordering::Relaxed);
+    ///             if r.start <= 1_000 && 1_000 < r.end {
+    ///                 Err("found 1000")
+    ///             } else {
+    ///                 Ok(r.end - r.start)
+    ///             }
+    ///         },
+    ///         |a, b| Ok(a + b),
+    ///     );
+    /// assert_eq!(result, Err("found 1000"));
+    /// assert!(processed.load(Ordering::Relaxed) < 100_000_000);
+    /// ```
+    fn try_run<ID, MF, RF, O, E>(
+        self,
+        identity: ID,
+        map_function: MF,
+        reduce_function: RF,
+    ) -> Result<O, E>
+    where
+        ID: Fn() -> O,
+        MF: Fn(I) -> Result<O, E> + Sync,
+        RF: Fn(O, O) -> Result<O, E> + Sync,
+        O: Send + Sync,
+        E: Send + Sync,
+    {
+        let failed = AtomicBool::new(false);
+        let check = |result: Result<O, E>| {
+            if result.is_err() {
+                failed.store(true, Ordering::Relaxed)
+            }
+            Some(result)
+        };
+        // no output yet is None, the first error wins
+        let reduce = |left: Option<Result<O, E>>, right: Option<Result<O, E>>| match (left, right) {
+            (Some(Ok(left)), Some(Ok(right))) => check(reduce_function(left, right)),
+            (Some(Err(error)), _) | (_, Some(Err(error))) => Some(Err(error)),
+            (None, output) | (output, None) => output,
+        };
+        let folder = Fold {
+            identity_op: || None,
+            fold_op: |o: Option<Result<O, E>>, i: AbortingDivisible<I>, limit: usize| {
+                let (todo_now, remaining) = divide_checked(i, limit);
+                if failed.load(Ordering::Relaxed) {
+                    (o, remaining)
+                } else {
+                    let new_result = check(map_function(todo_now.real_content));
+                    (reduce(o, new_result), remaining)
+                }
+            },
+            phantom: PhantomData,
+        };
+        let (input, policy, sizes) = self.input_policy_sizes();
+        let length = input.base_length();
+        // once failed, the aborting input looks empty and no more blocks are processed
+        AbortingDivisible {
+            real_content: input,
+            abort: &failed,
+        }
+        .chunks(sizes.chain(once(length)))
+        .map(|input| schedule(input, &folder, &reduce, policy))
+        .fold(None, &reduce)
+        .unwrap_or_else(|| Ok(identity()))
+    }
 }
 
 /********************************************************************************/
@@ -247,7 +849,8 @@ impl<I: DivisibleIntoBlocks, S: Iterator<Item = usize>, R: AdaptiveRunner<I, S>>
         .map(|_| ());
         let reduce = |_, _| ();
 
-        for input in input.chunks(sizes) {
+        let length = input.base_length();
+        for input in input.chunks(sizes.chain(once(length))) {
             schedule(input, &folder, &reduce, policy)
         }
     }
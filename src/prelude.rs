@@ -1,8 +1,8 @@
 pub use crate::iter::str::AdaptiveString;
 pub use crate::iter::{
-    AdaptiveBlockedIteratorRunner, AdaptiveIndexedIterator, AdaptiveIndexedIteratorRunner,
-    AdaptiveIterator, AdaptiveIteratorRunner, FromAdaptiveBlockedIterator,
-    FromAdaptiveIndexedIterator, IntoAdaptiveIterator,
+    AdaptiveIndexedIterator, AdaptiveIndexedIteratorRunner, AdaptiveIterator,
+    AdaptiveIteratorRunner, FromAdaptiveIterator, IntoAdaptiveIterator,
 };
 pub use crate::policy::{AdaptiveRunner, AllAdaptiveRunner, BlockAdaptiveRunner};
+pub use crate::slices::AdaptiveSlice;
 pub use crate::traits::{Divisible, DivisibleAtIndex, DivisibleIntoBlocks};
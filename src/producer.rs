@@ -0,0 +1,117 @@
+//! Use rayon's indexed parallel iterators as adaptive inputs.
+use crate::traits::IndexedPower;
+use crate::{Divisible, DivisibleAtIndex, DivisibleIntoBlocks};
+#[cfg(not(feature = "logs"))]
+use rayon::iter::plumbing::{Producer, ProducerCallback};
+#[cfg(not(feature = "logs"))]
+use rayon::iter::IndexedParallelIterator;
+#[cfg(feature = "logs")]
+use real_rayon::iter::plumbing::{Producer, ProducerCallback};
+#[cfg(feature = "logs")]
+use real_rayon::iter::IndexedParallelIterator;
+
+/// Rayon producer together with the number of items it produces.
+/// See `with_producer_input`.
+pub struct ProducerInput<P> {
+    producer: P,
+    length: usize,
+}
+
+// We never give out shared references to the producer,
+// so sharing the input only ever shares its length.
+unsafe impl<P: Send> Sync for ProducerInput<P> {}
+
+impl<P: Producer> ProducerInput<P> {
+    /// Wrap given producer of `length` items.
+    pub fn new(producer: P, length: usize) -> Self {
+        ProducerInput { producer, length }
+    }
+    /// Return the wrapped producer.
+    pub fn into_inner(self) -> P {
+        self.producer
+    }
+}
+
+impl<P: Producer> Divisible for ProducerInput<P> {
+    type Power = IndexedPower;
+    fn base_length(&self) -> usize {
+        self.length
+    }
+    fn divide(self) -> (Self, Self) {
+        let mid = self.length / 2;
+        self.divide_at(mid)
+    }
+}
+
+impl<P: Producer> DivisibleIntoBlocks for ProducerInput<P> {
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        assert!(index <= self.length);
+        let (left, right) = self.producer.split_at(index);
+        (
+            ProducerInput::new(left, index),
+            ProducerInput::new(right, self.length - index),
+        )
+    }
+}
+
+impl<P: Producer> DivisibleAtIndex for ProducerInput<P> {}
+
+impl<P: Producer> IntoIterator for ProducerInput<P> {
+    type Item = P::Item;
+    type IntoIter = P::IntoIter;
+    fn into_iter(self) -> Self::IntoIter {
+        self.producer.into_iter()
+    }
+}
+
+/// Code to run on the input obtained out of a rayon iterator.
+/// It needs to be generic over the producer type so cannot be a closure.
+pub trait InputCallback<T> {
+    type Output;
+    fn call<P: Producer<Item = T>>(self, input: ProducerInput<P>) -> Self::Output;
+}
+
+struct Callback<C> {
+    callback: C,
+    length: usize,
+}
+
+impl<T, C: InputCallback<T>> ProducerCallback<T> for Callback<C> {
+    type Output = C::Output;
+    fn callback<P: Producer<Item = T>>(self, producer: P) -> Self::Output {
+        self.callback
+            .call(ProducerInput::new(producer, self.length))
+    }
+}
+
+/// Turn given rayon indexed parallel iterator into an adaptive input
+/// and run the callback on it.
+///
+/// # Example
+///
+/// ```
+/// use rayon::prelude::*;
+/// use rayon_adaptive::prelude::*;
+/// use rayon_adaptive::producer::{with_producer_input, InputCallback, ProducerInput};
+/// use rayon::iter::plumbing::Producer;
+///
+/// struct Sum;
+/// impl InputCallback<u64> for Sum {
+///     type Output = u64;
+///     fn call<P: Producer<Item = u64>>(self, input: ProducerInput<P>) -> u64 {
+///         input.into_adapt_iter().sum()
+///     }
+/// }
+///
+/// let v: Vec<u64> = (0..10_000).collect();
+/// let s = with_producer_input(v.par_iter().map(|e| 2 * e), Sum);
+/// assert_eq!(s, 9_999 * 10_000);
+/// ```
+pub fn with_producer_input<I, C>(iterator: I, callback: C) -> C::Output
+where
+    I: IndexedParallelIterator,
+    C: InputCallback<I::Item>,
+{
+    let length = iterator.len();
+    iterator.with_producer(Callback { callback, length })
+}
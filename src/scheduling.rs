@@ -2,10 +2,12 @@
 use crate::atomiclist::{AtomicLink, AtomicList};
 use crate::depjoin;
 use crate::folders::Folder;
+use crate::poison::{reduce_poisonable, PanicSafeFolder, PanicSlot, Poisonable};
+use crate::policy::{environment_policy, AdaptiveParameters};
 use crate::prelude::*;
 use crate::smallchannel::{small_channel, SmallSender};
-use crate::traits::Divisible;
-use crate::utils::powers;
+use crate::traits::{divide_checked, Divisible};
+use crate::utils::growing_sizes;
 use crate::Policy;
 use rayon::{current_num_threads, Scope};
 #[cfg(feature = "logs")]
@@ -15,12 +17,16 @@ use std::cmp::min;
 use std::iter::once;
 use std::iter::repeat;
 use std::marker::PhantomData;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
 
 // we use this boolean to prevent fine grain parallelism when coarse grain
 // parallelism is still available in composed algorithms.
 thread_local!(static SEQUENCE: RefCell<bool> = RefCell::new(false));
 
+/// auto tuning stops increasing block sizes when a block lasts that long.
+const CALIBRATION_BLOCK_DURATION: Duration = Duration::from_micros(50);
+
 /// by default, min block size is log(n)
 fn default_min_block_size(n: usize) -> usize {
     let power = ((n as f64 / (n as f64).log(2.0) + 1.0).log(2.0) - 1.0).floor();
@@ -39,6 +45,37 @@ fn compute_size<F: Fn(usize) -> usize>(n: usize, sizing_function: F) -> usize {
     std::cmp::max(min(n / (2 * p), sizing_function(n)), 1)
 }
 
+// when not zero, all policies are replaced by a join down to this block size.
+static DETERMINISTIC_BLOCK_SIZE: AtomicUsize = AtomicUsize::new(0);
+
+/// Turn deterministic mode on (with given block size) or off (with `None`).
+/// In deterministic mode all policies get replaced by a `Join` down to the given
+/// block size, so divisions only depend on the inputs lengths and
+/// reductions always happen in the same order.
+/// This is useful for debugging reduction functions.
+/// Note that it applies to the whole process.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::prelude::*;
+/// use rayon_adaptive::set_deterministic;
+/// set_deterministic(Some(100));
+/// // we always get the same tree and the same string
+/// let tree = (0..1000)
+///     .into_adapt_iter()
+///     .fold(String::new, |s, _| if s.is_empty() { "x".to_string() } else { s })
+///     .reduce(|a, b| format!("({}{})", a, b));
+/// set_deterministic(None);
+/// assert_eq!(tree, "((((xx)(xx))((xx)(xx)))(((xx)(xx))((xx)(xx))))");
+/// ```
+pub fn set_deterministic(block_size: Option<usize>) {
+    assert_ne!(block_size, Some(0), "block size cannot be 0");
+    DETERMINISTIC_BLOCK_SIZE.store(block_size.unwrap_or(0), Ordering::SeqCst)
+}
+
+/// Schedule given input with given policy.
+/// The first panic of user code poisons all remaining work and is then re-raised here.
 pub(crate) fn schedule<F, RF>(
     input: F::Input,
     folder: &F,
@@ -49,6 +86,34 @@ where
     F: Folder,
     RF: Fn(F::Output, F::Output) -> F::Output + Sync,
 {
+    let slot = PanicSlot::new();
+    let output = schedule_policy(
+        Poisonable::new(input, &slot),
+        &PanicSafeFolder {
+            inner_folder: folder,
+            slot: &slot,
+        },
+        &|left, right| reduce_poisonable(&slot, reduce_function, left, right),
+        policy,
+    );
+    slot.into_result(output)
+}
+
+fn schedule_policy<F, RF>(
+    input: F::Input,
+    folder: &F,
+    reduce_function: &RF,
+    policy: Policy,
+) -> F::Output
+where
+    F: Folder,
+    RF: Fn(F::Output, F::Output) -> F::Output + Sync,
+{
+    let deterministic_block_size = DETERMINISTIC_BLOCK_SIZE.load(Ordering::Relaxed);
+    if deterministic_block_size != 0 {
+        return schedule_join(input, folder, reduce_function, deterministic_block_size);
+    }
+    let policy = environment_policy().unwrap_or(policy);
     SEQUENCE.with(|s| {
         if *s.borrow() || input.base_length() == 1 {
             schedule_sequential(input, folder)
@@ -59,22 +124,37 @@ where
                 Policy::Join(block_size)
                 | Policy::JoinContext(block_size)
                 | Policy::DepJoin(block_size)
+                | Policy::HelpFirst(block_size)
                 | Policy::Adaptive(block_size, _) => block_size,
-                Policy::Rayon => 1,
+                Policy::AdaptiveWith(parameters) => parameters.init_size,
+                Policy::Rayon | Policy::JoinDepth(_) | Policy::AutoTuned => 1,
             };
             match policy {
                 Policy::Sequential => schedule_sequential(input, folder),
                 Policy::Join(_) => schedule_join(input, folder, reduce_function, block_size),
+                Policy::JoinDepth(depth) => {
+                    schedule_join_depth(input, folder, reduce_function, depth)
+                }
                 Policy::JoinContext(_) => {
                     schedule_join_context(input, folder, reduce_function, block_size)
                 }
                 Policy::DepJoin(_) => schedule_depjoin(input, folder, reduce_function, block_size),
+                Policy::HelpFirst(_) => {
+                    schedule_help_first(input, folder, reduce_function, block_size)
+                }
                 Policy::Adaptive(min, max) => schedule_adaptive(
                     input,
                     folder.identity(),
                     folder,
                     reduce_function,
-                    (|_| min, |_| max),
+                    AdaptiveParameters::new(min, max),
+                ),
+                Policy::AdaptiveWith(parameters) => schedule_adaptive(
+                    input,
+                    folder.identity(),
+                    folder,
+                    reduce_function,
+                    parameters,
                 ),
                 Policy::DefaultPolicy => {
                     if block_size * 2 * current_num_threads() >= input.base_length() //TODO ASK should I call schedule_adaptive in this case?
@@ -96,10 +176,11 @@ where
                             folder.identity(),
                             folder,
                             reduce_function,
-                            (|_| block_size, |_| max_size),
+                            AdaptiveParameters::new(block_size, max_size),
                         )
                     }
                 }
+                Policy::AutoTuned => schedule_auto_tuned(input, folder, reduce_function),
                 Policy::Rayon => schedule_rayon_join_context(
                     input,
                     folder,
@@ -111,9 +192,115 @@ where
     })
 }
 
+/// A strategy for folding a `Divisible` input in parallel.
+///
+/// All `Policy` variants are schedulers, but downstream crates can plug their own
+/// strategies into `reduce_with`.
+/// Schedulers promise nothing on the order in which outputs get reduced,
+/// the ones always reducing left outputs with right outputs implement `OrderedScheduler`.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::prelude::*;
+/// use rayon_adaptive::{Folder, Scheduler};
+/// // cut once and fold each half sequentially in its own task.
+/// struct Halves;
+/// impl Scheduler for Halves {
+///     fn schedule<F, RF>(&self, input: F::Input, folder: &F, reduce_function: &RF) -> F::Output
+///     where
+///         F: Folder,
+///         RF: Fn(F::Output, F::Output) -> F::Output + Sync,
+///     {
+///         let run = |i: F::Input| {
+///             let length = i.base_length();
+///             let (io, i) = folder.fold(folder.identity(), i, length);
+///             folder.to_output(io, i)
+///         };
+///         let (left, right) = input.divide();
+///         let (left, right) = rayon::join(|| run(left), || run(right));
+///         reduce_function(left, right)
+///     }
+/// }
+/// let sum = (0..1000)
+///     .into_adapt_iter()
+///     .fold(|| 0, |s, e| s + e)
+///     .reduce_with(&Halves, |a, b| a + b);
+/// assert_eq!(sum, 999 * 500);
+/// ```
+pub trait Scheduler: Sync {
+    /// Fold given input with given folder, reducing all outputs with given reduction.
+    fn schedule<F, RF>(&self, input: F::Input, folder: &F, reduce_function: &RF) -> F::Output
+    where
+        F: Folder,
+        RF: Fn(F::Output, F::Output) -> F::Output + Sync;
+}
+
+impl Scheduler for Policy {
+    fn schedule<F, RF>(&self, input: F::Input, folder: &F, reduce_function: &RF) -> F::Output
+    where
+        F: Folder,
+        RF: Fn(F::Output, F::Output) -> F::Output + Sync,
+    {
+        schedule(input, folder, reduce_function, *self)
+    }
+}
+
+/// Schedulers which only ever reduce outputs of contiguous pieces,
+/// the output of the left piece coming first.
+/// Reductions can then be non-commutative (see `ActivatedInput::reduce_ordered_with`).
+///
+/// Nothing is checked: implementing this trait is a promise on the `schedule` method.
+pub trait OrderedScheduler: Scheduler {}
+
+// All policies divide pieces into a left and a right half and reduce
+// `reduce_function(left_output, right_output)`. This includes the adaptive
+// schedulers: the victim always keeps the left half and stolen outputs are
+// reduced on the right.
+impl OrderedScheduler for Policy {}
+
+/// Divide given input.
+/// When tracing, the division is recorded in a span.
+fn split<I: Divisible>(input: I) -> (I, I) {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::trace_span!(
+        "split",
+        length = input.base_length(),
+        thread = ?std::thread::current().id()
+    )
+    .entered();
+    input.divide()
+}
+
+/// Sequentially fold a block of given size.
+/// When logging, the block appears as a subgraph in the logs.
+/// When tracing, the block is recorded in a span.
+fn fold_block<F: Folder>(
+    folder: &F,
+    io: F::IntermediateOutput,
+    input: F::Input,
+    size: usize,
+) -> (F::IntermediateOutput, F::Input) {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::trace_span!(
+        "block",
+        length = size,
+        thread = ?std::thread::current().id()
+    )
+    .entered();
+    #[cfg(feature = "logs")]
+    {
+        subgraph("sequential fold", size, || folder.fold(io, input, size))
+    }
+    #[cfg(not(feature = "logs"))]
+    {
+        folder.fold(io, input, size)
+    }
+}
+
 fn schedule_sequential<F: Folder>(input: F::Input, folder: &F) -> F::Output {
     let len = input.base_length();
-    let (io, i) = folder.fold(folder.identity(), input, len);
+    let (io, i) = fold_block(folder, folder.identity(), input, len);
     folder.to_output(io, i)
 }
 
@@ -131,7 +318,7 @@ where
     if len <= block_size {
         schedule_sequential(input, folder)
     } else {
-        let (i1, i2) = input.divide();
+        let (i1, i2) = split(input);
         let (r1, r2) = rayon::join(
             || schedule_join(i1, folder, reduce_function, block_size),
             || schedule_join(i2, folder, reduce_function, block_size),
@@ -140,6 +327,28 @@ where
     }
 }
 
+fn schedule_join_depth<F, RF>(
+    input: F::Input,
+    folder: &F,
+    reduce_function: &RF,
+    depth: usize,
+) -> F::Output
+where
+    F: Folder,
+    RF: Fn(F::Output, F::Output) -> F::Output + Sync,
+{
+    if depth == 0 || input.base_length() <= 1 {
+        schedule_sequential(input, folder)
+    } else {
+        let (i1, i2) = split(input);
+        let (r1, r2) = rayon::join(
+            || schedule_join_depth(i1, folder, reduce_function, depth - 1),
+            || schedule_join_depth(i2, folder, reduce_function, depth - 1),
+        );
+        reduce_function(r1, r2)
+    }
+}
+
 fn schedule_join_context<F, RF>(
     input: F::Input,
     folder: &F,
@@ -154,7 +363,7 @@ where
     if len <= block_size {
         schedule_sequential(input, folder)
     } else {
-        let (i1, i2) = input.divide();
+        let (i1, i2) = split(input);
         let (r1, r2) = rayon::join_context(
             |_| schedule_join_context(i1, folder, reduce_function, block_size),
             |c| {
@@ -182,7 +391,7 @@ where
     if split_limit == 0 || input.base_length() == 1 {
         schedule_sequential(input, folder)
     } else {
-        let (i1, i2) = input.divide();
+        let (i1, i2) = split(input);
         let (r1, r2) = rayon::join_context(
             |_| schedule_rayon_join_context(i1, folder, reduce_function, split_limit / 2),
             |c| {
@@ -217,7 +426,7 @@ where
     if len <= min_size {
         schedule_sequential(input, folder)
     } else {
-        let (i1, i2) = input.divide();
+        let (i1, i2) = split(input);
         let (r1, r2) = rayon::join_context(
             |_| schedule_join_context_max_size(i1, folder, reduce_function, min_size, max_size),
             |c| {
@@ -235,6 +444,40 @@ where
     }
 }
 
+/// Spawn both halves as tasks and help executing tasks while waiting for them.
+fn schedule_help_first<F, RF>(
+    input: F::Input,
+    folder: &F,
+    reduce_function: &RF,
+    block_size: usize,
+) -> F::Output
+where
+    F: Folder,
+    RF: Fn(F::Output, F::Output) -> F::Output + Sync,
+{
+    if input.base_length() <= block_size {
+        schedule_sequential(input, folder)
+    } else {
+        let (i1, i2) = split(input);
+        let (mut r1, mut r2) = (None, None);
+        {
+            let (r1, r2) = (&mut r1, &mut r2);
+            rayon::scope(|s| {
+                s.spawn(move |_| {
+                    *r1 = Some(schedule_help_first(i1, folder, reduce_function, block_size))
+                });
+                s.spawn(move |_| {
+                    *r2 = Some(schedule_help_first(i2, folder, reduce_function, block_size))
+                });
+            });
+        }
+        reduce_function(
+            r1.expect("left task did not complete"),
+            r2.expect("right task did not complete"),
+        )
+    }
+}
+
 fn schedule_depjoin<F, RF>(
     input: F::Input,
     folder: &F,
@@ -249,7 +492,7 @@ where
     if len <= block_size {
         schedule_sequential(input, folder)
     } else {
-        let (i1, i2) = input.divide();
+        let (i1, i2) = split(input);
         depjoin(
             || schedule_depjoin(i1, folder, reduce_function, block_size),
             || schedule_depjoin(i2, folder, reduce_function, block_size),
@@ -258,17 +501,15 @@ where
     }
 }
 
-struct AdaptiveWorker<
-    'a,
-    'b,
-    F: Folder + 'b,
-    RF: Fn(F::Output, F::Output) -> F::Output + Sync + 'b,
-    MINSIZE: Fn(usize) -> usize + Send + Copy,
-    MAXSIZE: Fn(usize) -> usize + Send + Copy,
-> {
+/// Worker of the adaptive scheduler.
+/// It folds blocks sequentially and checks the `stolen` steal request flag between each block.
+/// When the flag is raised it splits its remaining input and answers the thief
+/// through its `sender` communication cell.
+struct AdaptiveWorker<'a, 'b, F: Folder + 'b, RF: Fn(F::Output, F::Output) -> F::Output + Sync + 'b>
+{
     input: F::Input,
     partial_output: F::IntermediateOutput,
-    block_sizes: (MINSIZE, MAXSIZE),
+    parameters: AdaptiveParameters,
     min_block_size: usize,
     max_block_size: usize,
     stolen: &'a AtomicBool,
@@ -278,29 +519,27 @@ struct AdaptiveWorker<
     phantom: PhantomData<(F::Output)>,
 }
 
-impl<'a, 'b, F, RF, MINSIZE, MAXSIZE> AdaptiveWorker<'a, 'b, F, RF, MINSIZE, MAXSIZE>
+impl<'a, 'b, F, RF> AdaptiveWorker<'a, 'b, F, RF>
 where
     F: Folder + 'b,
     RF: Fn(F::Output, F::Output) -> F::Output + Sync + 'b,
-    MINSIZE: Fn(usize) -> usize + Send + Copy,
-    MAXSIZE: Fn(usize) -> usize + Send + Copy,
 {
     fn new(
         input: F::Input,
         partial_output: F::IntermediateOutput,
-        block_sizes: (MINSIZE, MAXSIZE),
+        parameters: AdaptiveParameters,
         stolen: &'a AtomicBool,
         sender: SmallSender<F::Input>,
         folder: &'b F,
         reduce_function: &'b RF,
     ) -> Self {
-        let min_block_size = compute_size(input.base_length(), block_sizes.0);
-        let max_block_size = compute_size(input.base_length(), block_sizes.1);
+        let min_block_size = compute_size(input.base_length(), |_| parameters.init_size);
+        let max_block_size = compute_size(input.base_length(), |_| parameters.max_size);
 
         AdaptiveWorker {
             input,
             partial_output,
-            block_sizes,
+            parameters,
             min_block_size,
             max_block_size,
             stolen,
@@ -323,11 +562,11 @@ where
     //            self.partial_output,
     //            self.folder,
     //            self.reduce_function,
-    //            self.block_sizes,
+    //            self.parameters,
     //        )
     //    }
 
-    fn schedule(self) -> F::Output {
+    fn schedule(self, workers: &AtomicUsize) -> F::Output {
         // TODO: automate this min everywhere ?
         // TODO: factorize a little bit
         // start by computing a little bit in order to get a first output
@@ -336,38 +575,57 @@ where
         let stolen_bool = self.stolen;
         let folder = self.folder;
         let max_size = self.max_block_size;
-        match powers(self.min_block_size)
+        let threshold = self.parameters.sequential_threshold;
+        let max_duration = self.parameters.max_block_duration;
+        // blocks sizes limit ensuring we respect the maximal duration
+        let mut duration_limit = usize::MAX;
+        match growing_sizes(self.min_block_size, self.parameters.growth)
             .take_while(|&size| size < max_size)
             .chain(repeat(max_size))
             .take_while(|_| !stolen_bool.load(Ordering::Relaxed))
             .try_fold(
                 (partial_output, remaining_input),
                 |(output, input), size| {
-                    let checked_size = min(input.base_length(), size); //TODO: remove all these mins
-                    if checked_size > 0 {
-                        Ok(folder.fold(output, input, checked_size))
-                    } else {
+                    let remaining_length = input.base_length();
+                    if remaining_length == 0 {
                         Err(folder.to_output(output, input))
+                    } else if remaining_length <= threshold {
+                        // not worth stealing anymore, just finish it
+                        let (output, input) = fold_block(folder, output, input, remaining_length);
+                        Err(folder.to_output(output, input))
+                    } else {
+                        let size = min(min(remaining_length, size), duration_limit);
+                        let start = Instant::now();
+                        let result = fold_block(folder, output, input, size);
+                        if let Some(max_duration) = max_duration {
+                            let elapsed = start.elapsed().as_nanos().max(1);
+                            let limit = max_duration.as_nanos() * size as u128 / elapsed;
+                            duration_limit =
+                                std::cmp::max(limit.min(usize::MAX as u128) as usize, 1);
+                        }
+                        Ok(result)
                     }
                 },
             ) {
             Ok((mut output, mut remaining_input)) => {
                 let remaining_length = remaining_input.base_length();
-                if remaining_length > self.min_block_size {
-                    let (my_half, his_half) = remaining_input.divide();
+                if remaining_length > std::cmp::max(self.min_block_size, threshold) {
+                    let (my_half, his_half) = split(remaining_input);
                     if his_half.base_length() > 0 {
                         self.sender.send(his_half);
                     }
-                    schedule_adaptive(
+                    schedule_adaptive_workers(
                         my_half,
                         output,
                         self.folder,
                         self.reduce_function,
-                        self.block_sizes,
+                        self.parameters,
+                        workers,
                     )
                 } else {
                     if remaining_length != 0 {
-                        let final_result = folder.fold(output, remaining_input, remaining_length);
+                        let final_result =
+                            fold_block(folder, output, remaining_input, remaining_length);
                         output = final_result.0;
                         remaining_input = final_result.1;
                     }
@@ -379,22 +637,90 @@ where
     }
 }
 
-fn schedule_adaptive<F, RF, MINSIZE, MAXSIZE>(
+/// Fold sequentially blocks of doubling sizes until one takes long enough
+/// to amortize the cost of a task. We then go on adaptively from this size.
+fn schedule_auto_tuned<F, RF>(input: F::Input, folder: &F, reduce_function: &RF) -> F::Output
+where
+    F: Folder,
+    RF: Fn(F::Output, F::Output) -> F::Output + Sync,
+{
+    let length = input.base_length();
+    let size_limit = compute_size(length, |n| n);
+    let mut output = folder.identity();
+    let mut remaining_input = input;
+    let mut size = 1;
+    loop {
+        let remaining_length = remaining_input.base_length();
+        if remaining_length == 0 {
+            return folder.to_output(output, remaining_input);
+        }
+        let start = Instant::now();
+        let (new_output, new_input) =
+            fold_block(folder, output, remaining_input, min(size, remaining_length));
+        output = new_output;
+        remaining_input = new_input;
+        if start.elapsed() >= CALIBRATION_BLOCK_DURATION || size >= size_limit {
+            break;
+        }
+        size *= 2;
+    }
+    let max_size = std::cmp::max(compute_size(length, default_max_block_size), size);
+    schedule_adaptive(
+        remaining_input,
+        output,
+        folder,
+        reduce_function,
+        AdaptiveParameters::new(size, max_size),
+    )
+}
+
+/// Thief-splitting scheduler: work is only divided on demand.
+/// The stealing task raises a request flag and waits on a small channel
+/// for the victim to send it half of the remaining input
+/// (or to drop the channel if no work is left).
+fn schedule_adaptive<F, RF>(
+    input: F::Input,
+    partial_output: F::IntermediateOutput,
+    folder: &F,
+    reduce_function: &RF,
+    parameters: AdaptiveParameters,
+) -> F::Output
+where
+    F: Folder,
+    RF: Fn(F::Output, F::Output) -> F::Output + Sync,
+{
+    let workers = AtomicUsize::new(1);
+    schedule_adaptive_workers(
+        input,
+        partial_output,
+        folder,
+        reduce_function,
+        parameters,
+        &workers,
+    )
+}
+
+/// Adaptive scheduling with `workers` counting the threads working on the computation.
+fn schedule_adaptive_workers<F, RF>(
     input: F::Input,
     partial_output: F::IntermediateOutput,
     folder: &F,
     reduce_function: &RF,
-    block_sizes: (MINSIZE, MAXSIZE),
+    parameters: AdaptiveParameters,
+    workers: &AtomicUsize,
 ) -> F::Output
 where
     F: Folder,
     RF: Fn(F::Output, F::Output) -> F::Output + Sync,
-    MINSIZE: Fn(usize) -> usize + Send + Copy,
-    MAXSIZE: Fn(usize) -> usize + Send + Copy,
 {
     let size = input.base_length();
-    if size <= compute_size(size, block_sizes.0) {
-        let (io, i) = folder.fold(partial_output, input, size);
+    if size
+        <= std::cmp::max(
+            compute_size(size, |_| parameters.init_size),
+            parameters.sequential_threshold,
+        )
+    {
+        let (io, i) = fold_block(folder, partial_output, input, size);
         folder.to_output(io, i)
     } else {
         let stolen = &AtomicBool::new(false);
@@ -403,7 +729,7 @@ where
         let worker = AdaptiveWorker::new(
             input,
             partial_output,
-            block_sizes,
+            parameters,
             stolen,
             sender,
             folder,
@@ -412,27 +738,41 @@ where
 
         //TODO depjoin instead of join
         let (o1, maybe_o2) = rayon::join(
-            move || worker.schedule(),
+            move || worker.schedule(workers),
             move || {
-                stolen.store(true, Ordering::Relaxed);
-                let input: F::Input;
-                #[cfg(feature = "logs")]
-                {
-                    let option = subgraph("waiting", 1, || receiver.recv());
-                    input = option?;
-                }
-                #[cfg(not(feature = "logs"))]
-                {
-                    input = receiver.recv()?;
+                if workers.fetch_add(1, Ordering::SeqCst) >= parameters.max_threads {
+                    // enough threads already, let the worker go on alone
+                    workers.fetch_sub(1, Ordering::SeqCst);
+                    return None;
                 }
-                assert!(input.base_length() > 0);
-                Some(schedule_adaptive(
-                    input,
-                    folder.identity(),
-                    folder,
-                    reduce_function,
-                    block_sizes,
-                ))
+                let steal = || {
+                    stolen.store(true, Ordering::Relaxed);
+                    let input: F::Input;
+                    #[cfg(feature = "logs")]
+                    {
+                        let option = subgraph("waiting", 1, || receiver.recv());
+                        input = option?;
+                    }
+                    #[cfg(not(feature = "logs"))]
+                    {
+                        input = receiver.recv()?;
+                    }
+                    if input.base_length() == 0 {
+                        // the computation got cancelled or poisoned in the meantime
+                        return None;
+                    }
+                    Some(schedule_adaptive_workers(
+                        input,
+                        folder.identity(),
+                        folder,
+                        reduce_function,
+                        parameters,
+                        workers,
+                    ))
+                };
+                let output = steal();
+                workers.fetch_sub(1, Ordering::SeqCst);
+                output
             },
         );
 
@@ -457,6 +797,8 @@ enum FoldElement<I, O2> {
     Output(O2),
 }
 
+/// Fold on the calling thread with the help of other threads.
+/// The first panic of user code poisons all remaining work and is then re-raised here.
 pub(crate) fn fold_with_help<F, O1, FOLD1, RET, S>(
     input: F::Input,
     o1: O1,
@@ -474,13 +816,72 @@ where
     RET: Fn(O1, F::Output) -> O1 + Sync,
     S: Iterator<Item = usize> + Send,
 {
-    let (min_size, max_size) = match policy {
-        Policy::Adaptive(min_size, max_size) => (min_size, max_size),
-        Policy::DefaultPolicy => (
+    let panic_slot = PanicSlot::new();
+    let slot = &panic_slot;
+    let output = fold_with_help_policy(
+        Poisonable::new(input, slot),
+        Some(o1),
+        |o1: Option<O1>, i: Poisonable<F::Input>, limit| match (o1, i.input) {
+            (Some(o1), Some(input)) => match slot.catch(|| fold1(o1, input, limit)) {
+                Some((o1, input)) => (Some(o1), Poisonable::new(input, slot)),
+                None => (None, Poisonable { input: None, slot }),
+            },
+            (_, input) => (None, Poisonable { input, slot }),
+        },
+        &PanicSafeFolder {
+            inner_folder: slave_folder,
+            slot,
+        },
+        |o1: Option<O1>, o2: Option<F::Output>| match (o1, o2) {
+            (Some(o1), Some(o2)) => slot.catch(|| retrieve(o1, o2)),
+            _ => None,
+        },
+        sizes,
+        policy,
+    );
+    panic_slot.into_result(output)
+}
+
+fn fold_with_help_policy<F, O1, FOLD1, RET, S>(
+    input: F::Input,
+    o1: O1,
+    fold1: FOLD1,
+    slave_folder: &F,
+    retrieve: RET,
+    sizes: S,
+    policy: Policy,
+) -> O1
+where
+    F: Folder + Send,
+    O1: Send,
+    F::Input: DivisibleIntoBlocks,
+    FOLD1: Fn(O1, F::Input, usize) -> (O1, F::Input) + Sync,
+    RET: Fn(O1, F::Output) -> O1 + Sync,
+    S: Iterator<Item = usize> + Send,
+{
+    // overriding policies we cannot help with are ignored
+    let policy = match environment_policy() {
+        Some(
+            overriding_policy @ (Policy::Sequential
+            | Policy::Adaptive(..)
+            | Policy::AdaptiveWith(_)
+            | Policy::DefaultPolicy),
+        ) => overriding_policy,
+        _ => policy,
+    };
+    let parameters = match policy {
+        Policy::Sequential => {
+            // nothing to help with, we just fold everything on the calling thread
+            let length = input.base_length();
+            return fold1(o1, input, length).0;
+        }
+        Policy::Adaptive(min_size, max_size) => AdaptiveParameters::new(min_size, max_size),
+        Policy::AdaptiveWith(parameters) => parameters,
+        Policy::DefaultPolicy => AdaptiveParameters::new(
             compute_size(input.base_length(), default_min_block_size),
             compute_size(input.base_length(), default_max_block_size),
         ),
-        _ => panic!("for now only adaptive or default policies for help"),
+        _ => panic!("for now only sequential, adaptive or default policies for help"),
     };
     let input_length = input.base_length();
     let stolen_stuffs: &AtomicList<(Option<F::Output>, Option<F::Input>)> = &AtomicList::new();
@@ -496,16 +897,9 @@ where
                 }))
             })
             .fold(o1, |o1, element| match element {
-                FoldElement::Input(i) => master_work(
-                    s,
-                    o1,
-                    i,
-                    &fold1,
-                    slave_folder,
-                    stolen_stuffs,
-                    min_size,
-                    max_size,
-                ),
+                FoldElement::Input(i) => {
+                    master_work(s, o1, i, &fold1, slave_folder, stolen_stuffs, parameters)
+                }
                 FoldElement::Output(o2) => retrieve(o1, o2),
             })
     })
@@ -514,8 +908,7 @@ where
 fn spawn_stealing_task<'scope, F>(
     scope: &Scope<'scope>,
     slave_folder: &'scope F,
-    min_size: usize,
-    max_size: usize,
+    parameters: AdaptiveParameters,
 ) -> SmallSender<AtomicLink<(Option<F::Output>, Option<F::Input>)>>
 where
     F: Folder + 'scope + Send,
@@ -535,7 +928,7 @@ where
         if stolen_input.is_none() {
             return;
         }
-        slave_work(s, stolen_input.unwrap(), slave_folder, min_size, max_size)
+        slave_work(s, stolen_input.unwrap(), slave_folder, parameters)
     });
     sender
 }
@@ -547,8 +940,7 @@ fn master_work<'scope, F, O1, FOLD1>(
     fold: &FOLD1,
     slave_folder: &'scope F,
     stolen_stuffs: &AtomicList<(Option<F::Output>, Option<F::Input>)>,
-    min_size: usize,
-    max_size: usize,
+    parameters: AdaptiveParameters,
 ) -> O1
 where
     F: Folder + 'scope + Send,
@@ -559,11 +951,11 @@ where
     let mut input = input;
     let mut current_output = init;
     loop {
-        let sender = spawn_stealing_task(scope, slave_folder, min_size, max_size);
+        let sender = spawn_stealing_task(scope, slave_folder, parameters);
         // let's work sequentially until stolen
-        match powers(min_size)
-            .take_while(|&p| p < max_size)
-            .chain(repeat(max_size))
+        match growing_sizes(parameters.init_size, parameters.growth)
+            .take_while(|&p| p < parameters.max_size)
+            .chain(repeat(parameters.max_size))
             .take_while(|_| !sender.receiver_is_waiting())
             .try_fold((current_output, input), |(output, input), size| {
                 let checked_size = min(input.base_length(), size); //TODO: remove all these mins
@@ -574,8 +966,8 @@ where
                 }
             }) {
             Ok((output, remaining_input)) => {
-                if remaining_input.base_length() > min_size {
-                    let (my_half, his_half) = remaining_input.divide();
+                if remaining_input.base_length() > parameters.init_size {
+                    let (my_half, his_half) = split(remaining_input);
                     if his_half.base_length() > 0 {
                         let stolen_node = stolen_stuffs.push_front((None, Some(his_half)));
                         sender.send(stolen_node);
@@ -598,8 +990,7 @@ fn slave_work<'scope, F>(
     scope: &Scope<'scope>,
     node: AtomicLink<(Option<F::Output>, Option<F::Input>)>,
     slave_folder: &'scope F,
-    min_size: usize,
-    max_size: usize,
+    parameters: AdaptiveParameters,
 ) where
     F: Folder + 'scope + Send,
     F::Input: DivisibleIntoBlocks + 'scope,
@@ -607,16 +998,16 @@ fn slave_work<'scope, F>(
     let mut input = node.take().unwrap().1.unwrap();
     let mut o2 = slave_folder.identity();
     loop {
-        let sender = spawn_stealing_task(scope, slave_folder, min_size, max_size);
+        let sender = spawn_stealing_task(scope, slave_folder, parameters);
         // let's work sequentially until stolen
-        match powers(min_size)
-            .take_while(|&p| p < max_size)
-            .chain(repeat(max_size))
+        match growing_sizes(parameters.init_size, parameters.growth)
+            .take_while(|&p| p < parameters.max_size)
+            .chain(repeat(parameters.max_size))
             .take_while(|_| !sender.receiver_is_waiting() && !node.requested())
             .try_fold((o2, input), |(output2, input), size| {
                 let checked_size = min(input.base_length(), size); //TODO: remove all these mins
                 if checked_size > 0 {
-                    Ok(slave_folder.fold(output2, input, checked_size))
+                    Ok(fold_block(slave_folder, output2, input, checked_size))
                 } else {
                     Err((output2, input))
                 }
@@ -624,7 +1015,7 @@ fn slave_work<'scope, F>(
             Ok((output2, remaining_input)) => {
                 if node.requested() {
                     // retrieval operations are prioritized over steal ops
-                    let (completed, remaining_input) = remaining_input.divide_at(0);
+                    let (completed, remaining_input) = divide_checked(remaining_input, 0);
                     node.replace((
                         Some(slave_folder.to_output(output2, completed)),
                         Some(remaining_input),
@@ -633,8 +1024,8 @@ fn slave_work<'scope, F>(
                 } else {
                     // check if enough is left
                     let length = remaining_input.base_length();
-                    if length > min_size {
-                        let (my_half, his_half) = remaining_input.divide();
+                    if length > parameters.init_size {
+                        let (my_half, his_half) = split(remaining_input);
                         // TODO: have an empty method
                         if his_half.base_length() > 0 {
                             let stolen_node = (&node).split((None, Some(his_half)));
@@ -645,7 +1036,7 @@ fn slave_work<'scope, F>(
                     } else {
                         // just fold it locally
                         let (intermediate_output, input) =
-                            slave_folder.fold(output2, remaining_input, length);
+                            fold_block(slave_folder, output2, remaining_input, length);
                         node.replace((
                             Some(slave_folder.to_output(intermediate_output, input)),
                             None,
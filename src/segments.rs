@@ -0,0 +1,133 @@
+//! Non contiguous inputs: lists of segments processed as one.
+use crate::{Divisible, DivisibleAtIndex, DivisibleIntoBlocks};
+use std::iter::{once, Flatten};
+use std::vec;
+
+/// List of segments (for example slices of several buffers) placed back-to-back.
+/// We divide between whole segments while there are several of them
+/// and then inside the remaining one.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::prelude::*;
+/// use rayon_adaptive::Segments;
+/// let buffers: Vec<Vec<u64>> = (0..100).map(|i| (i * 100..(i + 1) * 100).collect()).collect();
+/// let segments = Segments::new(buffers.iter().map(|b| b.as_slice()).collect());
+/// assert_eq!(segments.base_length(), 10_000);
+/// let s: u64 = segments.into_adapt_iter().sum();
+/// assert_eq!(s, 9_999 * 5_000);
+/// ```
+pub struct Segments<S> {
+    segments: Vec<S>,
+    length: usize,
+}
+
+impl<S: Divisible> Segments<S> {
+    /// Place given segments back-to-back.
+    pub fn new(segments: Vec<S>) -> Self {
+        let length = segments.iter().map(|s| s.base_length()).sum();
+        Segments { segments, length }
+    }
+    /// Return remaining segments.
+    pub fn segments(&self) -> &[S] {
+        &self.segments
+    }
+    /// Return remaining segments.
+    pub fn into_inner(self) -> Vec<S> {
+        self.segments
+    }
+    // divide between whole segments, first `count` going left.
+    fn divide_segments(mut self, count: usize) -> (Self, Self) {
+        let right_segments = self.segments.split_off(count);
+        let right_length = right_segments.iter().map(|s| s.base_length()).sum();
+        (
+            Segments {
+                segments: self.segments,
+                length: self.length - right_length,
+            },
+            Segments {
+                segments: right_segments,
+                length: right_length,
+            },
+        )
+    }
+}
+
+impl<S: Divisible> Divisible for Segments<S> {
+    type Power = S::Power;
+    fn base_length(&self) -> usize {
+        self.length
+    }
+    fn divide(self) -> (Self, Self) {
+        if self.segments.len() > 1 {
+            // segments boundary closest to the middle, leaving a segment on each side
+            let half = self.length / 2;
+            let mut left_length = self.segments[0].base_length();
+            let mut count = 1;
+            while count < self.segments.len() - 1
+                && left_length + self.segments[count].base_length() / 2 < half
+            {
+                left_length += self.segments[count].base_length();
+                count += 1;
+            }
+            self.divide_segments(count)
+        } else {
+            let mut segments = self.segments;
+            match segments.pop() {
+                Some(segment) => {
+                    let (left, right) = segment.divide();
+                    (Segments::new(vec![left]), Segments::new(vec![right]))
+                }
+                None => (Segments::new(Vec::new()), Segments::new(Vec::new())),
+            }
+        }
+    }
+}
+
+impl<S: DivisibleIntoBlocks> DivisibleIntoBlocks for Segments<S> {
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        assert!(index <= self.length);
+        // find the segment containing the index
+        let mut left_length = 0;
+        let mut count = 0;
+        for segment in &self.segments {
+            let length = segment.base_length();
+            if left_length + length > index {
+                break;
+            }
+            left_length += length;
+            count += 1;
+        }
+        if left_length == index {
+            return self.divide_segments(count);
+        }
+        let length = self.length;
+        let (mut left, right) = self.divide_segments(count);
+        let mut right_segments = right.segments.into_iter();
+        let (inner_left, inner_right) = right_segments
+            .next()
+            .expect("index out of segments")
+            .divide_at(index - left_length);
+        left.length += inner_left.base_length();
+        left.segments.push(inner_left);
+        let right_length = length - left.length;
+        (
+            left,
+            Segments {
+                segments: once(inner_right).chain(right_segments).collect(),
+                length: right_length,
+            },
+        )
+    }
+}
+
+impl<S: DivisibleAtIndex> DivisibleAtIndex for Segments<S> {}
+
+impl<S: IntoIterator> IntoIterator for Segments<S> {
+    type Item = S::Item;
+    type IntoIter = Flatten<vec::IntoIter<S>>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.segments.into_iter().flatten()
+    }
+}
@@ -0,0 +1,274 @@
+//! Two dimensional views over flat buffers, divided into rectangular tiles.
+use crate::traits::BasicPower;
+use crate::Divisible;
+use std::marker::PhantomData;
+use std::slice;
+
+/// Rectangular tile of a matrix stored row by row in a flat buffer.
+/// Tiles get divided along their largest dimension, so square tiles
+/// get alternately divided along rows and columns.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::prelude::*;
+/// use rayon_adaptive::Slice2D;
+/// let v: Vec<u64> = (0..100 * 200).collect();
+/// let sum = Slice2D::new(&v, 200)
+///     .partial_fold(
+///         || 0,
+///         |s, tile, _| {
+///             let s = s + tile.rows().map(|row| row.iter().sum::<u64>()).sum::<u64>();
+///             (s, tile.split_rows_at(tile.height()).1)
+///         },
+///     )
+///     .reduce(|a, b| a + b);
+/// assert_eq!(sum, v.iter().sum());
+/// ```
+pub struct Slice2D<'a, T> {
+    // buffer starting at our first element
+    data: &'a [T],
+    stride: usize,
+    height: usize,
+    width: usize,
+    first_row: usize,
+    first_column: usize,
+}
+
+impl<'a, T> Slice2D<'a, T> {
+    /// View given buffer as a matrix with rows of given width.
+    pub fn new(data: &'a [T], width: usize) -> Self {
+        assert!(
+            width > 0 && data.len().is_multiple_of(width),
+            "invalid matrix width"
+        );
+        Slice2D {
+            data,
+            stride: width,
+            height: data.len() / width,
+            width,
+            first_row: 0,
+            first_column: 0,
+        }
+    }
+    /// Number of rows in the tile.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    /// Number of columns in the tile.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    /// Coordinates (row, column) in the whole matrix of the tile's first element.
+    pub fn origin(&self) -> (usize, usize) {
+        (self.first_row, self.first_column)
+    }
+    /// Return given row of the tile.
+    pub fn row(&self, row: usize) -> &'a [T] {
+        assert!(row < self.height);
+        &self.data[row * self.stride..row * self.stride + self.width]
+    }
+    /// Iterate on all rows of the tile.
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        (0..self.height).map(move |row| self.row(row))
+    }
+    /// Divide into rows above and below given one.
+    pub fn split_rows_at(self, row: usize) -> (Self, Self) {
+        assert!(row <= self.height);
+        let offset = if row == self.height {
+            self.data.len()
+        } else {
+            row * self.stride
+        };
+        (
+            Slice2D {
+                height: row,
+                ..self
+            },
+            Slice2D {
+                data: &self.data[offset..],
+                height: self.height - row,
+                first_row: self.first_row + row,
+                ..self
+            },
+        )
+    }
+    /// Divide into columns left and right of given one.
+    pub fn split_columns_at(self, column: usize) -> (Self, Self) {
+        assert!(column <= self.width);
+        let offset = if self.height == 0 {
+            self.data.len()
+        } else {
+            column
+        };
+        (
+            Slice2D {
+                width: column,
+                ..self
+            },
+            Slice2D {
+                data: &self.data[offset..],
+                width: self.width - column,
+                first_column: self.first_column + column,
+                ..self
+            },
+        )
+    }
+}
+
+impl<'a, T> Clone for Slice2D<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for Slice2D<'a, T> {}
+
+impl<'a, T: Sync> Divisible for Slice2D<'a, T> {
+    type Power = BasicPower;
+    fn base_length(&self) -> usize {
+        self.height * self.width
+    }
+    fn divide(self) -> (Self, Self) {
+        if self.height >= self.width {
+            self.split_rows_at(self.height / 2)
+        } else {
+            self.split_columns_at(self.width / 2)
+        }
+    }
+}
+
+/// Mutable rectangular tile of a matrix stored row by row in a flat buffer.
+/// See `Slice2D`.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::prelude::*;
+/// use rayon_adaptive::Slice2DMut;
+/// let mut v = vec![0; 100 * 200];
+/// Slice2DMut::new(&mut v, 200).partial_for_each(|mut tile, _| {
+///     let (first_row, first_column) = tile.origin();
+///     for row in 0..tile.height() {
+///         for (column, e) in tile.row_mut(row).iter_mut().enumerate() {
+///             *e = (first_row + row) * (first_column + column);
+///         }
+///     }
+///     let height = tile.height();
+///     tile.split_rows_at(height).1
+/// });
+/// assert_eq!(v[3 * 200 + 7], 21);
+/// ```
+pub struct Slice2DMut<'a, T> {
+    // pointer on our first element
+    data: *mut T,
+    stride: usize,
+    height: usize,
+    width: usize,
+    first_row: usize,
+    first_column: usize,
+    phantom: PhantomData<&'a mut T>,
+}
+
+// tiles never overlap
+unsafe impl<'a, T: Send> Send for Slice2DMut<'a, T> {}
+unsafe impl<'a, T: Sync> Sync for Slice2DMut<'a, T> {}
+
+impl<'a, T> Slice2DMut<'a, T> {
+    /// View given buffer as a matrix with rows of given width.
+    pub fn new(data: &'a mut [T], width: usize) -> Self {
+        assert!(
+            width > 0 && data.len().is_multiple_of(width),
+            "invalid matrix width"
+        );
+        Slice2DMut {
+            height: data.len() / width,
+            data: data.as_mut_ptr(),
+            stride: width,
+            width,
+            first_row: 0,
+            first_column: 0,
+            phantom: PhantomData,
+        }
+    }
+    /// Number of rows in the tile.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+    /// Number of columns in the tile.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+    /// Coordinates (row, column) in the whole matrix of the tile's first element.
+    pub fn origin(&self) -> (usize, usize) {
+        (self.first_row, self.first_column)
+    }
+    /// Return given row of the tile.
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.height);
+        unsafe { slice::from_raw_parts(self.data.add(row * self.stride), self.width) }
+    }
+    /// Return given row of the tile, mutably.
+    pub fn row_mut(&mut self, row: usize) -> &mut [T] {
+        assert!(row < self.height);
+        unsafe { slice::from_raw_parts_mut(self.data.add(row * self.stride), self.width) }
+    }
+    /// Divide into rows above and below given one.
+    pub fn split_rows_at(self, row: usize) -> (Self, Self) {
+        assert!(row <= self.height);
+        let data = if row == self.height {
+            self.data
+        } else {
+            unsafe { self.data.add(row * self.stride) }
+        };
+        (
+            Slice2DMut {
+                height: row,
+                ..self
+            },
+            Slice2DMut {
+                data,
+                height: self.height - row,
+                first_row: self.first_row + row,
+                ..self
+            },
+        )
+    }
+    /// Divide into columns left and right of given one.
+    pub fn split_columns_at(self, column: usize) -> (Self, Self) {
+        assert!(column <= self.width);
+        let data = if self.height == 0 {
+            self.data
+        } else {
+            unsafe { self.data.add(column) }
+        };
+        (
+            Slice2DMut {
+                width: column,
+                ..self
+            },
+            Slice2DMut {
+                data,
+                width: self.width - column,
+                first_column: self.first_column + column,
+                ..self
+            },
+        )
+    }
+}
+
+impl<'a, T: Send + Sync> Divisible for Slice2DMut<'a, T> {
+    type Power = BasicPower;
+    fn base_length(&self) -> usize {
+        self.height * self.width
+    }
+    fn divide(self) -> (Self, Self) {
+        if self.height >= self.width {
+            let middle = self.height / 2;
+            self.split_rows_at(middle)
+        } else {
+            let middle = self.width / 2;
+            self.split_columns_at(middle)
+        }
+    }
+}
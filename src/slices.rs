@@ -1,11 +1,15 @@
 //! We provide here `EdibleSlice` and `EatingIterator` for better composability.
 
 use crate::traits::IndexedPower;
-use crate::{fuse_slices, Divisible, DivisibleIntoBlocks};
+use crate::{fuse_slices, Divisible, DivisibleAtIndex, DivisibleIntoBlocks};
+use std::cmp::min;
+use std::convert::TryInto;
 use std::iter::Peekable;
 use std::ptr;
 use std::slice::Iter;
 use std::slice::IterMut;
+use std::slice::Windows as SliceWindows;
+use std::slice::{ChunksExact as SliceChunks, ChunksExactMut as SliceChunksMut};
 
 /// A slice you can consume slowly.
 #[derive(Debug)]
@@ -276,3 +280,504 @@ impl<'a, T: 'a> Iterator for EatingIteratorMut<'a, T> {
         next_one
     }
 }
+
+/// Slice divided into blocks which can also see `overlap` neighbouring elements
+/// on each side (the halo), as needed by stencil computations.
+/// See `overlapping_blocks`.
+#[derive(Debug)]
+pub struct OverlappingBlocks<'a, T: 'a> {
+    // the whole slice
+    slice: &'a [T],
+    // range of elements we own
+    start: usize,
+    end: usize,
+    overlap: usize,
+}
+
+/// View given slice as blocks seeing their `overlap` neighbours on each side.
+/// Iterating yields for each element the window of its neighbours,
+/// truncated on the slice's borders.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::overlapping_blocks;
+/// use rayon_adaptive::prelude::*;
+/// let v: Vec<u32> = (0..1_000).collect();
+/// // three points stencil
+/// let smoothed: Vec<u32> = overlapping_blocks(&v, 1)
+///     .into_adapt_iter()
+///     .map(|window| window.iter().sum::<u32>() / window.len() as u32)
+///     .collect();
+/// assert_eq!(smoothed[0], 0);
+/// assert_eq!(smoothed[500], 500);
+/// assert_eq!(smoothed.len(), v.len());
+/// ```
+pub fn overlapping_blocks<T>(slice: &[T], overlap: usize) -> OverlappingBlocks<'_, T> {
+    OverlappingBlocks {
+        slice,
+        start: 0,
+        end: slice.len(),
+        overlap,
+    }
+}
+
+impl<'a, T: 'a> OverlappingBlocks<'a, T> {
+    /// Return the elements we own.
+    pub fn core(&self) -> &'a [T] {
+        &self.slice[self.start..self.end]
+    }
+    /// Return the elements we own together with our halo.
+    pub fn block(&self) -> &'a [T] {
+        let (start, end) = self.halo_bounds(self.start, self.end);
+        &self.slice[start..end]
+    }
+    /// Index in the whole slice of our first owned element.
+    pub fn offset(&self) -> usize {
+        self.start
+    }
+    // extend given range with the halo
+    fn halo_bounds(&self, start: usize, end: usize) -> (usize, usize) {
+        (
+            start.saturating_sub(self.overlap),
+            min(end.saturating_add(self.overlap), self.slice.len()),
+        )
+    }
+}
+
+impl<'a, T: 'a + Sync> Divisible for OverlappingBlocks<'a, T> {
+    type Power = IndexedPower;
+    fn base_length(&self) -> usize {
+        self.end - self.start
+    }
+    fn divide(self) -> (Self, Self) {
+        let mid = self.base_length() / 2;
+        self.divide_at(mid)
+    }
+}
+
+impl<'a, T: 'a + Sync> DivisibleIntoBlocks for OverlappingBlocks<'a, T> {
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        assert!(index <= self.base_length());
+        let middle = self.start + index;
+        (
+            OverlappingBlocks {
+                end: middle,
+                ..self
+            },
+            OverlappingBlocks {
+                start: middle,
+                ..self
+            },
+        )
+    }
+}
+
+impl<'a, T: 'a + Sync> DivisibleAtIndex for OverlappingBlocks<'a, T> {}
+
+/// Iterator on the windows around each element of some `OverlappingBlocks`.
+pub struct Windows<'a, T: 'a> {
+    blocks: OverlappingBlocks<'a, T>,
+}
+
+impl<'a, T: 'a> Windows<'a, T> {
+    fn window(&self, index: usize) -> &'a [T] {
+        let (start, end) = self.blocks.halo_bounds(index, index + 1);
+        &self.blocks.slice[start..end]
+    }
+}
+
+impl<'a, T: 'a> Iterator for Windows<'a, T> {
+    type Item = &'a [T];
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.blocks.start == self.blocks.end {
+            None
+        } else {
+            let window = self.window(self.blocks.start);
+            self.blocks.start += 1;
+            Some(window)
+        }
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.blocks.end - self.blocks.start;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T: 'a> DoubleEndedIterator for Windows<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.blocks.start == self.blocks.end {
+            None
+        } else {
+            self.blocks.end -= 1;
+            Some(self.window(self.blocks.end))
+        }
+    }
+}
+
+impl<'a, T: 'a> ExactSizeIterator for Windows<'a, T> {}
+
+impl<'a, T: 'a> IntoIterator for OverlappingBlocks<'a, T> {
+    type Item = &'a [T];
+    type IntoIter = Windows<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        Windows { blocks: self }
+    }
+}
+
+/// All windows of `size` consecutive elements of a slice.
+/// Like the halos of `OverlappingBlocks`, pieces share the `size - 1` elements
+/// around each division so no window gets lost.
+/// See `AdaptiveSlice::adaptive_windows`.
+#[derive(Debug)]
+pub struct AdaptiveWindows<'a, T: 'a> {
+    // all elements of our windows
+    slice: &'a [T],
+    size: usize,
+}
+
+impl<'a, T: 'a + Sync> Divisible for AdaptiveWindows<'a, T> {
+    type Power = IndexedPower;
+    fn base_length(&self) -> usize {
+        (self.slice.len() + 1).saturating_sub(self.size)
+    }
+    fn divide(self) -> (Self, Self) {
+        let mid = self.base_length() / 2;
+        self.divide_at(mid)
+    }
+}
+
+impl<'a, T: 'a + Sync> DivisibleIntoBlocks for AdaptiveWindows<'a, T> {
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        assert!(index <= self.base_length());
+        let left_end = min(index + self.size - 1, self.slice.len());
+        (
+            AdaptiveWindows {
+                slice: &self.slice[..left_end],
+                size: self.size,
+            },
+            AdaptiveWindows {
+                slice: &self.slice[index..],
+                size: self.size,
+            },
+        )
+    }
+}
+
+impl<'a, T: 'a + Sync> DivisibleAtIndex for AdaptiveWindows<'a, T> {}
+
+impl<'a, T: 'a> IntoIterator for AdaptiveWindows<'a, T> {
+    type Item = &'a [T];
+    type IntoIter = SliceWindows<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.slice.windows(self.size)
+    }
+}
+
+/// Chunks of `size` consecutive elements of a slice (the last one may be shorter).
+/// See `AdaptiveSlice::adaptive_chunks`.
+#[derive(Debug)]
+pub struct AdaptiveChunks<'a, T: 'a> {
+    slice: &'a [T],
+    size: usize,
+}
+
+impl<'a, T: 'a + Sync> Divisible for AdaptiveChunks<'a, T> {
+    type Power = IndexedPower;
+    fn base_length(&self) -> usize {
+        self.slice.len().div_ceil(self.size)
+    }
+    fn divide(self) -> (Self, Self) {
+        let mid = self.base_length() / 2;
+        self.divide_at(mid)
+    }
+}
+
+impl<'a, T: 'a + Sync> DivisibleIntoBlocks for AdaptiveChunks<'a, T> {
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        let length = self.slice.len();
+        let (left, right) = self.slice.split_at(min(index * self.size, length));
+        (
+            AdaptiveChunks {
+                slice: left,
+                size: self.size,
+            },
+            AdaptiveChunks {
+                slice: right,
+                size: self.size,
+            },
+        )
+    }
+}
+
+impl<'a, T: 'a + Sync> DivisibleAtIndex for AdaptiveChunks<'a, T> {}
+
+impl<'a, T: 'a> IntoIterator for AdaptiveChunks<'a, T> {
+    type Item = &'a [T];
+    type IntoIter = std::slice::Chunks<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.slice.chunks(self.size)
+    }
+}
+
+/// Mutable chunks of `size` consecutive elements of a slice (the last one may be shorter).
+/// See `AdaptiveSlice::adaptive_chunks_mut`.
+#[derive(Debug)]
+pub struct AdaptiveChunksMut<'a, T: 'a> {
+    slice: &'a mut [T],
+    size: usize,
+}
+
+impl<'a, T: 'a + Send + Sync> Divisible for AdaptiveChunksMut<'a, T> {
+    type Power = IndexedPower;
+    fn base_length(&self) -> usize {
+        self.slice.len().div_ceil(self.size)
+    }
+    fn divide(self) -> (Self, Self) {
+        let mid = self.base_length() / 2;
+        self.divide_at(mid)
+    }
+}
+
+impl<'a, T: 'a + Send + Sync> DivisibleIntoBlocks for AdaptiveChunksMut<'a, T> {
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        let length = self.slice.len();
+        let (left, right) = self.slice.split_at_mut(min(index * self.size, length));
+        (
+            AdaptiveChunksMut {
+                slice: left,
+                size: self.size,
+            },
+            AdaptiveChunksMut {
+                slice: right,
+                size: self.size,
+            },
+        )
+    }
+}
+
+impl<'a, T: 'a + Send + Sync> DivisibleAtIndex for AdaptiveChunksMut<'a, T> {}
+
+impl<'a, T: 'a> IntoIterator for AdaptiveChunksMut<'a, T> {
+    type Item = &'a mut [T];
+    type IntoIter = std::slice::ChunksMut<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.slice.chunks_mut(self.size)
+    }
+}
+
+/// Adaptive views of slices.
+pub trait AdaptiveSlice<T> {
+    /// Divisible on all windows of `size` consecutive elements.
+    /// Panics if `size` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let v: Vec<u64> = (0..10_000).collect();
+    /// let sums: Vec<u64> = v
+    ///     .adaptive_windows(3)
+    ///     .into_adapt_iter()
+    ///     .map(|w| w.iter().sum::<u64>())
+    ///     .collect();
+    /// assert_eq!(sums.len(), 9_998);
+    /// assert_eq!(sums[100], 303);
+    /// assert_eq!(v[..2].adaptive_windows(3).base_length(), 0);
+    /// ```
+    fn adaptive_windows(&self, size: usize) -> AdaptiveWindows<'_, T>;
+    /// Divisible on chunks of `size` elements, the last one being possibly shorter.
+    /// Unlike `DivisibleIntoBlocks::chunks` the chunks are the items,
+    /// processed in parallel.
+    /// Panics if `size` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let v: Vec<u32> = (0..1_000).collect();
+    /// let maxima: Vec<u32> = v
+    ///     .adaptive_chunks(64)
+    ///     .into_adapt_iter()
+    ///     .map(|c| *c.iter().max().unwrap())
+    ///     .collect();
+    /// assert_eq!(maxima.len(), 16);
+    /// assert_eq!(maxima[15], 999);
+    /// ```
+    fn adaptive_chunks(&self, size: usize) -> AdaptiveChunks<'_, T>;
+    /// Divisible on mutable chunks of `size` elements, the last one being possibly shorter.
+    /// Panics if `size` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let mut v: Vec<u32> = (0..1_000).collect();
+    /// v.adaptive_chunks_mut(10)
+    ///     .into_adapt_iter()
+    ///     .for_each(|c| c.reverse());
+    /// assert_eq!(&v[..12], &[9, 8, 7, 6, 5, 4, 3, 2, 1, 0, 19, 18]);
+    /// ```
+    fn adaptive_chunks_mut(&mut self, size: usize) -> AdaptiveChunksMut<'_, T>;
+}
+
+impl<T> AdaptiveSlice<T> for [T] {
+    fn adaptive_windows(&self, size: usize) -> AdaptiveWindows<'_, T> {
+        assert!(size > 0, "windows need at least one element");
+        AdaptiveWindows { slice: self, size }
+    }
+    fn adaptive_chunks(&self, size: usize) -> AdaptiveChunks<'_, T> {
+        assert!(size > 0, "chunks need at least one element");
+        AdaptiveChunks { slice: self, size }
+    }
+    fn adaptive_chunks_mut(&mut self, size: usize) -> AdaptiveChunksMut<'_, T> {
+        assert!(size > 0, "chunks need at least one element");
+        AdaptiveChunksMut { slice: self, size }
+    }
+}
+
+/// Slice viewed as contiguous arrays of `N` elements.
+/// See `chunks_exact`.
+#[derive(Debug)]
+pub struct ChunksExact<'a, T: 'a, const N: usize> {
+    // length is a multiple of N
+    slice: &'a [T],
+}
+
+/// View given slice as arrays of `N` elements.
+/// We return the divisible arrays together with the remaining elements
+/// (less than `N`) at the end of the slice, which are up to the caller.
+/// Knowing `N` at compile time helps vectorizing kernels on the arrays.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::chunks_exact;
+/// use rayon_adaptive::prelude::*;
+/// let v: Vec<u32> = (0..1_003).collect();
+/// let (chunks, remainder) = chunks_exact::<4, _>(&v);
+/// assert_eq!(chunks.base_length(), 250);
+/// assert_eq!(remainder, &[1_000, 1_001, 1_002]);
+/// let s: u32 = chunks
+///     .into_adapt_iter()
+///     .map(|c: &[u32; 4]| c[0] + c[1] + c[2] + c[3])
+///     .sum();
+/// assert_eq!(s + remainder.iter().sum::<u32>(), 1_002 * 1_003 / 2);
+/// ```
+pub fn chunks_exact<const N: usize, T>(slice: &[T]) -> (ChunksExact<'_, T, N>, &[T]) {
+    assert!(N > 0, "chunks need at least one element");
+    let (slice, remainder) = slice.split_at(slice.len() / N * N);
+    (ChunksExact { slice }, remainder)
+}
+
+impl<'a, T: 'a, const N: usize> ChunksExact<'a, T, N> {
+    /// Return all elements of our arrays as one flat slice.
+    pub fn as_slice(&self) -> &'a [T] {
+        self.slice
+    }
+}
+
+impl<'a, T: 'a + Sync, const N: usize> Divisible for ChunksExact<'a, T, N> {
+    type Power = IndexedPower;
+    fn base_length(&self) -> usize {
+        self.slice.len() / N
+    }
+    fn divide(self) -> (Self, Self) {
+        let mid = self.base_length() / 2;
+        self.divide_at(mid)
+    }
+}
+
+impl<'a, T: 'a + Sync, const N: usize> DivisibleIntoBlocks for ChunksExact<'a, T, N> {
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.slice.split_at(index * N);
+        (ChunksExact { slice: left }, ChunksExact { slice: right })
+    }
+}
+
+impl<'a, T: 'a + Sync, const N: usize> DivisibleAtIndex for ChunksExact<'a, T, N> {}
+
+impl<'a, T: 'a, const N: usize> IntoIterator for ChunksExact<'a, T, N> {
+    type Item = &'a [T; N];
+    type IntoIter = std::iter::Map<SliceChunks<'a, T>, fn(&'a [T]) -> &'a [T; N]>;
+    fn into_iter(self) -> Self::IntoIter {
+        let to_array: fn(&'a [T]) -> &'a [T; N] = |chunk| chunk.try_into().unwrap();
+        self.slice.chunks_exact(N).map(to_array)
+    }
+}
+
+/// Mutable slice viewed as contiguous arrays of `N` elements.
+/// See `chunks_exact_mut`.
+#[derive(Debug)]
+pub struct ChunksExactMut<'a, T: 'a, const N: usize> {
+    // length is a multiple of N
+    slice: &'a mut [T],
+}
+
+/// View given mutable slice as arrays of `N` elements.
+/// See `chunks_exact`.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::chunks_exact_mut;
+/// use rayon_adaptive::prelude::*;
+/// let mut v = vec![1.0f64; 1_002];
+/// let (chunks, remainder) = chunks_exact_mut::<4, _>(&mut v);
+/// chunks.into_adapt_iter().for_each(|c: &mut [f64; 4]| {
+///     for e in c.iter_mut() {
+///         *e *= 2.0
+///     }
+/// });
+/// for e in remainder {
+///     *e *= 2.0
+/// }
+/// assert!(v.iter().all(|&e| e == 2.0));
+/// ```
+pub fn chunks_exact_mut<const N: usize, T>(
+    slice: &mut [T],
+) -> (ChunksExactMut<'_, T, N>, &mut [T]) {
+    assert!(N > 0, "chunks need at least one element");
+    let length = slice.len() / N * N;
+    let (slice, remainder) = slice.split_at_mut(length);
+    (ChunksExactMut { slice }, remainder)
+}
+
+impl<'a, T: 'a, const N: usize> ChunksExactMut<'a, T, N> {
+    /// Return all elements of our arrays as one flat mutable slice.
+    pub fn into_slice(self) -> &'a mut [T] {
+        self.slice
+    }
+}
+
+impl<'a, T: 'a + Send + Sync, const N: usize> Divisible for ChunksExactMut<'a, T, N> {
+    type Power = IndexedPower;
+    fn base_length(&self) -> usize {
+        self.slice.len() / N
+    }
+    fn divide(self) -> (Self, Self) {
+        let mid = self.base_length() / 2;
+        self.divide_at(mid)
+    }
+}
+
+impl<'a, T: 'a + Send + Sync, const N: usize> DivisibleIntoBlocks for ChunksExactMut<'a, T, N> {
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.slice.split_at_mut(index * N);
+        (
+            ChunksExactMut { slice: left },
+            ChunksExactMut { slice: right },
+        )
+    }
+}
+
+impl<'a, T: 'a + Send + Sync, const N: usize> DivisibleAtIndex for ChunksExactMut<'a, T, N> {}
+
+impl<'a, T: 'a, const N: usize> IntoIterator for ChunksExactMut<'a, T, N> {
+    type Item = &'a mut [T; N];
+    type IntoIter = std::iter::Map<SliceChunksMut<'a, T>, fn(&'a mut [T]) -> &'a mut [T; N]>;
+    fn into_iter(self) -> Self::IntoIter {
+        let to_array: fn(&'a mut [T]) -> &'a mut [T; N] = |chunk| chunk.try_into().unwrap();
+        self.slice.chunks_exact_mut(N).map(to_array)
+    }
+}
@@ -1,11 +1,21 @@
 //! This module contains all traits enabling us to express some parallelism.
 use std;
-use std::iter::{empty, Empty};
-use std::ops::Range;
-use std::ptr;
+use std::borrow::Cow;
+use std::cmp::min;
+use std::error::Error;
+use std::fmt;
+use std::iter::{empty, once, Empty, Take};
+use std::mem;
+use std::ops::{Range, RangeFrom, RangeInclusive};
+use std::sync::atomic::AtomicBool;
+use std::sync::Arc;
 
 use crate::chunks::Chunks;
+use crate::iter::zip::Zip;
+#[cfg(feature = "numa")]
+use crate::numa::NumaDivisible;
 use crate::policy::ParametrizedInput;
+use crate::utils::{AbortingDivisible, Costed, Enumerate, Rev, StepBy, Weighted};
 use crate::Policy;
 
 // markers for specialization
@@ -34,35 +44,368 @@ pub trait Divisible: Sized + Send + Sync {
             sizes: empty(),
         }
     }
+    /// Stop processing as soon as given token gets set.
+    /// The token is checked between sequential blocks and
+    /// all remaining work is then abandoned, returning partial results.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// use std::sync::atomic::{AtomicBool, Ordering};
+    /// let token = AtomicBool::new(false);
+    /// let s: usize = (0..10_000).into_adapt_iter().with_cancellation(&token).sum();
+    /// assert_eq!(s, 9_999 * 5_000);
+    /// token.store(true, Ordering::Relaxed);
+    /// let s: usize = (0..10_000).into_adapt_iter().with_cancellation(&token).sum();
+    /// assert_eq!(s, 0);
+    /// ```
+    fn with_cancellation(self, token: &AtomicBool) -> AbortingDivisible<'_, Self> {
+        AbortingDivisible {
+            real_content: self,
+            abort: token,
+        }
+    }
+    /// Flip our sides: what was on the right now comes first.
+    /// This enables consuming inputs from the end, for example for reverse searches.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let last = (0..10_000u64)
+    ///     .rev_divisible()
+    ///     .into_adapt_iter()
+    ///     .find_first(|x| x % 7 == 0);
+    /// assert_eq!(last, Some(9_996));
+    /// ```
+    fn rev_divisible(self) -> Rev<Self> {
+        Rev { real_content: self }
+    }
+    /// Remember the global index of our first element in all pieces.
+    /// Iterating yields `(index, item)` pairs.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let v: Vec<i64> = (0..10_000).map(|i: i64| (i - 6_543).abs()).collect();
+    /// // position of the minimum
+    /// let (position, _) = v
+    ///     .as_slice()
+    ///     .enumerate_divisible()
+    ///     .map_reduce(
+    ///         |piece| {
+    ///             let (start, slice) = piece.into_parts();
+    ///             let (i, m) = slice.iter().enumerate().min_by_key(|(_, &e)| e).unwrap();
+    ///             (start + i, *m)
+    ///         },
+    ///         |a, b| if b.1 < a.1 { b } else { a },
+    ///     );
+    /// assert_eq!(position, 6_543);
+    /// ```
+    fn enumerate_divisible(self) -> Enumerate<Self> {
+        Enumerate {
+            real_content: self,
+            offset: 0,
+        }
+    }
+}
+
+/// Error returned when dividing an input at an impossible place.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DivisionError {
+    /// Requested index is past the end of the input.
+    IndexOutOfBounds { index: usize, length: usize },
+}
+
+impl fmt::Display for DivisionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DivisionError::IndexOutOfBounds { index, length } => write!(
+                f,
+                "cannot divide input of length {} at index {}",
+                length, index
+            ),
+        }
+    }
+}
+
+impl Error for DivisionError {}
+
+// Divide at given index, panicking with an understandable message when out of range.
+// Schedulers go through here rather than relying on each divide_at implementation.
+pub(crate) fn divide_checked<I: DivisibleIntoBlocks>(input: I, index: usize) -> (I, I) {
+    input
+        .try_divide_at(index)
+        .unwrap_or_else(|error| panic!("{}", error))
 }
 
 pub trait DivisibleIntoBlocks: Divisible {
     /// Divide ourselves where requested.
     fn divide_at(self, index: usize) -> (Self, Self);
+    /// Divide ourselves where requested, failing if index is past our base length.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// use rayon_adaptive::DivisionError;
+    /// let v = vec![1, 2, 3];
+    /// let (left, right) = v.as_slice().try_divide_at(1).unwrap();
+    /// assert_eq!((left, right), (&[1][..], &[2, 3][..]));
+    /// assert_eq!(
+    ///     v.as_slice().try_divide_at(4),
+    ///     Err(DivisionError::IndexOutOfBounds { index: 4, length: 3 })
+    /// );
+    /// ```
+    fn try_divide_at(self, index: usize) -> Result<(Self, Self), DivisionError> {
+        let length = self.base_length();
+        if index > length {
+            Err(DivisionError::IndexOutOfBounds { index, length })
+        } else {
+            Ok(self.divide_at(index))
+        }
+    }
     /// Divide ourselves keeping right part in self.
     /// Returns the left part.
     /// NB: this is useful for iterators creation.
-    fn cut_left_at(&mut self, index: usize) -> Self {
-        // there is a lot of unsafe going on here.
-        // I think it's ok. rust uses the same trick for moving iterators (vecs for example)
-        unsafe {
-            let my_copy = ptr::read(self);
-            let (left, right) = my_copy.divide_at(index);
-            let pointer_to_self = self as *mut Self;
-            ptr::write(pointer_to_self, right);
-            left
+    /// We are left with the default value if `divide_at` panics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let mut r = 0..10;
+    /// assert_eq!(r.cut_left_at(3), 0..3);
+    /// assert_eq!(r, 3..10);
+    /// ```
+    fn cut_left_at(&mut self, index: usize) -> Self
+    where
+        Self: Default,
+    {
+        let (left, right) = mem::take(self).divide_at(index);
+        *self = right;
+        left
+    }
+    /// Divide ourselves into `n` pieces of near equal lengths.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// assert_eq!((0..10).divide_into(3), vec![0..3, 3..6, 6..10]);
+    /// let pieces = (0..100_000u64).divide_into(rayon::current_num_threads());
+    /// assert_eq!(pieces.len(), rayon::current_num_threads());
+    /// ```
+    fn divide_into(self, n: usize) -> Vec<Self> {
+        assert!(n > 0, "we need at least one piece");
+        let length = self.base_length() as u128;
+        // index at which piece i starts
+        let boundary = |i: usize| (length * i as u128 / n as u128) as usize;
+        let mut pieces = Vec::with_capacity(n);
+        let mut remaining = self;
+        for i in 1..n {
+            let (piece, right) = remaining.divide_at(boundary(i) - boundary(i - 1));
+            pieces.push(piece);
+            remaining = right;
         }
+        pieces.push(remaining);
+        pieces
+    }
+    /// Divide ourselves keeping left part in self.
+    /// Returns the right part.
+    /// NB: this is useful for iterating backwards.
+    /// We are left with the default value if `divide_at` panics.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let v: Vec<u32> = (0..10).collect();
+    /// let mut s = v.as_slice();
+    /// let tail = s.cut_right_at(7);
+    /// assert_eq!(s, &v[..7]);
+    /// assert_eq!(tail, &v[7..]);
+    /// ```
+    fn cut_right_at(&mut self, index: usize) -> Self
+    where
+        Self: Default,
+    {
+        let (left, right) = mem::take(self).divide_at(index);
+        *self = left;
+        right
+    }
+    /// Divide ourselves at given fraction of our base length.
+    /// Ratio must be between 0 and 1, for example 1/3 leaves a third of the work on the left.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let v: Vec<u32> = (0..9).collect();
+    /// let (left, right) = v.as_slice().divide_at_ratio(1.0 / 3.0);
+    /// assert_eq!((left.len(), right.len()), (3, 6));
+    /// ```
+    fn divide_at_ratio(self, ratio: f64) -> (Self, Self) {
+        assert!(
+            (0.0..=1.0).contains(&ratio),
+            "division ratio {} is not between 0 and 1",
+            ratio
+        );
+        let length = self.base_length();
+        let index = min(length, (length as f64 * ratio).round() as usize);
+        self.divide_at(index)
     }
     /// Get a sequential iterator on chunks of Self of given sizes.
     fn chunks<S: Iterator<Item = usize>>(self, sizes: S) -> Chunks<Self, S> {
         Chunks {
-            remaining: self,
+            remaining: Some(self),
             remaining_sizes: sizes,
         }
     }
+    /// Divide where costs are balanced instead of at mid length.
+    /// `cost(length, start)` estimates the cost of processing `length` elements
+    /// starting at index `start`. It needs to increase with `length`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// // processing element i takes time i
+    /// let s: usize = (0..10_000)
+    ///     .into_adapt_iter()
+    ///     .with_cost(|length, start| {
+    ///         let end = (start + length) as f64;
+    ///         let start = start as f64;
+    ///         end * end - start * start
+    ///     })
+    ///     .map(|i| (0..i).count())
+    ///     .sum();
+    /// assert_eq!(s, 9_999 * 5_000);
+    /// ```
+    fn with_cost<C>(self, cost: C) -> Costed<Self, C>
+    where
+        C: Fn(usize, usize) -> f64 + Send + Sync + Copy,
+    {
+        Costed {
+            real_content: self,
+            cost,
+            start: 0,
+        }
+    }
+    /// Divide at median weight instead of mid length.
+    /// We need exactly one weight per element (counted in base length).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// // first element is as costly as all the others together
+    /// let v: Vec<u64> = (0..1_000).collect();
+    /// let weights = (0..1_000).map(|i| if i == 0 { 999.0 } else { 1.0 });
+    /// let (left, right) = v.as_slice().with_weights(weights).divide();
+    /// assert_eq!((left.base_length(), right.base_length()), (1, 999));
+    /// // last element is more costly than all the others together
+    /// let weights = (0..1_000).map(|i| if i == 999 { 1_000.0 } else { 1.0 });
+    /// let (left, right) = v.as_slice().with_weights(weights).divide();
+    /// assert_eq!((left.base_length(), right.base_length()), (999, 1));
+    /// let s: u64 = v.as_slice().with_weights(vec![1.0; 1_000]).into_adapt_iter().sum();
+    /// assert_eq!(s, 999 * 500);
+    /// ```
+    fn with_weights<W: IntoIterator<Item = f64>>(self, weights: W) -> Weighted<Self> {
+        let prefix_sums: Vec<f64> = once(0.0)
+            .chain(weights.into_iter().scan(0.0, |sum, weight| {
+                *sum += weight;
+                Some(*sum)
+            }))
+            .collect();
+        assert_eq!(
+            prefix_sums.len(),
+            self.base_length() + 1,
+            "we need one weight per element"
+        );
+        Weighted {
+            real_content: self,
+            prefix_sums: Arc::new(prefix_sums),
+            start: 0,
+        }
+    }
+
+    /// Consider our content to be spread evenly and contiguously on
+    /// given number of NUMA nodes.
+    /// We will then always divide at nodes boundaries first.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let v: Vec<u64> = (0..10_000).collect();
+    /// let s: u64 = v.as_slice().with_numa_nodes(2).into_adapt_iter().sum();
+    /// assert_eq!(s, 9_999 * 5_000);
+    /// ```
+    #[cfg(feature = "numa")]
+    fn with_numa_nodes(self, nodes: usize) -> NumaDivisible<Self> {
+        assert!(nodes > 0, "we need at least one node");
+        let total_length = self.base_length();
+        NumaDivisible {
+            real_content: self,
+            nodes,
+            total_length,
+            start: 0,
+        }
+    }
 }
 
-pub trait DivisibleAtIndex: DivisibleIntoBlocks {}
+pub trait DivisibleAtIndex: DivisibleIntoBlocks {
+    /// Pair ourselves with another input of same length.
+    /// Both inputs are then always divided at the same index.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// let mut a = vec![1u32; 1_000];
+    /// let b: Vec<u32> = (0..1_000).collect();
+    /// a.as_mut_slice()
+    ///     .zip_divisible(b.as_slice())
+    ///     .partial_for_each(|zipped, limit| {
+    ///         let (todo, remaining) = zipped.divide_at(limit);
+    ///         for (x, y) in todo {
+    ///             *x += *y
+    ///         }
+    ///         remaining
+    ///     });
+    /// assert!(a.iter().zip(0..).all(|(&x, i)| x == i + 1));
+    /// ```
+    fn zip_divisible<D: DivisibleAtIndex>(self, other: D) -> Zip<Self, D> {
+        Zip::new(self, other)
+    }
+    /// Keep only every `step`-th element, starting with the first one.
+    /// Divisions happen on the strided indices, so all pieces stay aligned on kept elements.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use rayon_adaptive::prelude::*;
+    /// // interleaved stereo samples: sum the left channel
+    /// let samples: Vec<u64> = (0..10_000).collect();
+    /// let left = samples.as_slice().step_by_divisible(2);
+    /// assert_eq!(left.base_length(), 5_000);
+    /// let s: u64 = left.into_adapt_iter().cloned().sum();
+    /// assert_eq!(s, 4_999 * 5_000);
+    /// let (left, right) = (0..10u32).step_by_divisible(3).divide_at(2);
+    /// assert_eq!(left.into_iter().collect::<Vec<_>>(), vec![0, 3]);
+    /// assert_eq!(right.into_iter().collect::<Vec<_>>(), vec![6, 9]);
+    /// ```
+    fn step_by_divisible(self, step: usize) -> StepBy<Self> {
+        assert!(step > 0, "step must be positive");
+        StepBy {
+            real_content: self,
+            step,
+        }
+    }
+}
 
 impl<'a, T: Sync> Divisible for &'a [T] {
     type Power = IndexedPower;
@@ -103,26 +446,364 @@ impl<'a, T: 'a + Sync + Send> DivisibleIntoBlocks for &'a mut [T] {
 
 impl<'a, T: 'a + Sync + Send> DivisibleAtIndex for &'a mut [T] {}
 
-//TODO: be more generic but it seems complex
-impl Divisible for Range<usize> {
+/// Borrowed or owned slices are divisible.
+/// Borrowed slices are divided without copies but owned vectors get divided
+/// by moving their right part into a new (allocated) vector.
+/// Recursive divisions of owned vectors therefore move each element once per
+/// division level, in `O(n log(n))` total, on top of the allocations.
+/// When possible, divide a `Cow::Borrowed` of the vector instead.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::prelude::*;
+/// use std::borrow::Cow;
+/// fn sum(input: Cow<[u64]>) -> u64 {
+///     input.map_reduce(|c| c.iter().sum(), |a, b| a + b)
+/// }
+/// let v: Vec<u64> = (0..10_000).collect();
+/// assert_eq!(sum(Cow::Borrowed(&v)), 9_999 * 5_000);
+/// assert_eq!(sum(Cow::Owned(v)), 9_999 * 5_000);
+/// ```
+impl<T: Clone + Send + Sync> Divisible for Cow<'_, [T]> {
     type Power = IndexedPower;
     fn base_length(&self) -> usize {
         self.len()
     }
     fn divide(self) -> (Self, Self) {
-        let mid = self.start + ExactSizeIterator::len(&self) / 2;
+        let mid = self.len() / 2;
+        self.divide_at(mid)
+    }
+}
+
+impl<T: Clone + Send + Sync> DivisibleIntoBlocks for Cow<'_, [T]> {
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        match self {
+            Cow::Borrowed(slice) => {
+                let (left, right) = slice.split_at(index);
+                (Cow::Borrowed(left), Cow::Borrowed(right))
+            }
+            Cow::Owned(mut vector) => {
+                let right = vector.split_off(index);
+                (Cow::Owned(vector), Cow::Owned(right))
+            }
+        }
+    }
+}
+
+impl<T: Clone + Send + Sync> DivisibleAtIndex for Cow<'_, [T]> {}
+
+// closest char boundary to given index (preferring lower indices)
+// we never return an empty side if a char boundary exists strictly inside
+fn char_boundary_around(s: &str, index: usize) -> usize {
+    if index >= s.len() {
+        return s.len();
+    }
+    let lower = (0..=index)
+        .rev()
+        .find(|&i| s.is_char_boundary(i))
+        .unwrap_or(0);
+    let higher = (index..s.len())
+        .find(|&i| s.is_char_boundary(i))
+        .unwrap_or(s.len());
+    if lower == 0 {
+        higher
+    } else if higher == s.len() || index - lower <= higher - index {
+        lower
+    } else {
+        higher
+    }
+}
+
+// number of bytes after the first char's first one
+fn first_char_extra_bytes(s: &str) -> usize {
+    s.chars().next().map(|c| c.len_utf8() - 1).unwrap_or(0)
+}
+
+/// Strings are divided at the char boundary closest to requested indices.
+/// Lengths are counted in bytes, except for the first char which counts as one.
+/// This way any index strictly between 0 and the length gives two non-empty
+/// pieces and a single char (of length 1) is never divided further.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::prelude::*;
+/// let text = "àéîõü".repeat(1_000);
+/// let (left, right) = text.as_str().divide_at(2);
+/// assert_eq!(left, "à");
+/// assert_eq!(right.len(), text.len() - 2);
+/// let count = text
+///     .as_str()
+///     .partial_fold(|| 0, |c, s, limit| {
+///         let (todo, remaining) = s.divide_at(limit);
+///         (c + todo.chars().count(), remaining)
+///     })
+///     .reduce(|a, b| a + b);
+/// assert_eq!(count, 5_000);
+/// ```
+///
+/// Multi-bytes chars are never cut, even with tiny blocks:
+///
+/// ```
+/// use rayon_adaptive::prelude::*;
+/// use rayon_adaptive::Policy;
+/// let emojis = "😀😃😄😁".repeat(10);
+/// assert_eq!(emojis.as_str().base_length(), emojis.len() - 3);
+/// assert_eq!("😀".base_length(), 1);
+/// let count = emojis
+///     .as_str()
+///     .with_policy(Policy::Join(1))
+///     .map_reduce(|s| s.chars().count(), |a, b| a + b);
+/// assert_eq!(count, 40);
+/// ```
+impl Divisible for &str {
+    type Power = BlockedPower;
+    fn base_length(&self) -> usize {
+        self.len() - first_char_extra_bytes(self)
+    }
+    fn divide(self) -> (Self, Self) {
+        let mid = self.base_length() / 2;
+        self.divide_at(mid)
+    }
+}
+
+impl DivisibleIntoBlocks for &str {
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        let byte_index = if index == 0 {
+            0
+        } else {
+            index + first_char_extra_bytes(self)
+        };
+        self.split_at(char_boundary_around(self, byte_index))
+    }
+}
+
+mod step {
+    /// Integers we can use in divisible ranges.
+    pub trait Step: Copy + PartialOrd + Send + Sync {
+        /// Number of integers between start (included) and end (excluded).
+        fn distance(start: Self, end: Self) -> usize;
+        /// Integer `count` steps after `start`.
+        fn forward(start: Self, count: usize) -> Self;
+    }
+
+    macro_rules! implement_step {
+        ($($integer:ty)*) => {$(
+            impl Step for $integer {
+                fn distance(start: Self, end: Self) -> usize {
+                    std::cmp::max(end as i128 - start as i128, 0) as usize
+                }
+                fn forward(start: Self, count: usize) -> Self {
+                    (start as i128 + count as i128) as Self
+                }
+            }
+        )*};
+    }
+
+    implement_step!(u8 u16 u32 u64 usize i8 i16 i32 i64 isize);
+
+    // surrogates are not chars, we number chars without them
+    const SURROGATES_START: u32 = 0xD800;
+    const SURROGATES_COUNT: u32 = 0x800;
+
+    fn char_index(c: char) -> u32 {
+        let code = c as u32;
+        if code >= SURROGATES_START {
+            code - SURROGATES_COUNT
+        } else {
+            code
+        }
+    }
+
+    impl Step for char {
+        fn distance(start: Self, end: Self) -> usize {
+            char_index(end).saturating_sub(char_index(start)) as usize
+        }
+        fn forward(start: Self, count: usize) -> Self {
+            let index = char_index(start) + count as u32;
+            let code = if index >= SURROGATES_START {
+                index + SURROGATES_COUNT
+            } else {
+                index
+            };
+            std::char::from_u32(code).expect("char range overflow")
+        }
+    }
+}
+
+/// Ranges of all integer types (and of chars) are divisible.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::prelude::*;
+/// use rayon_adaptive::utils::powers;
+/// let s: i64 = (-50_000i64..50_000).into_adapt_iter().sum();
+/// assert_eq!(s, -50_000);
+/// // large index spaces
+/// let found = (0..u64::MAX)
+///     .into_adapt_iter()
+///     .by_blocks(powers(100))
+///     .find_first(|&x| x % 1000 == 999);
+/// assert_eq!(found, Some(999));
+/// // all chars but the surrogates
+/// let count = ('\0'..char::MAX).into_adapt_iter().map(|_| 1).sum::<usize>();
+/// assert_eq!(count, 0x10FFFF - 0x800);
+/// ```
+impl<T: step::Step> Divisible for Range<T> {
+    type Power = IndexedPower;
+    fn base_length(&self) -> usize {
+        T::distance(self.start, self.end)
+    }
+    fn divide(self) -> (Self, Self) {
+        let mid = self.base_length() / 2;
+        self.divide_at(mid)
+    }
+}
+
+impl<T: step::Step> DivisibleIntoBlocks for Range<T> {
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        let mid = T::forward(self.start, index);
         (self.start..mid, mid..self.end)
     }
 }
 
-//TODO: be more generic but it seems complex
-impl DivisibleIntoBlocks for Range<usize> {
+impl<T: step::Step> DivisibleAtIndex for Range<T> {}
+
+// empty inclusive range: the exhausted flag only gets set by iterating
+fn empty_inclusive_range<T: step::Step>(bound: T) -> RangeInclusive<T>
+where
+    RangeInclusive<T>: Iterator,
+{
+    let mut range = bound..=bound;
+    range.next();
+    range
+}
+
+/// Inclusive ranges of all integer types are divisible.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::prelude::*;
+/// let s: u32 = (1..=1_000u32).into_adapt_iter().sum();
+/// assert_eq!(s, 1_000 * 1_001 / 2);
+/// let (left, right) = (0..=9u8).divide_at(3);
+/// assert_eq!((left, right), (0..=2, 3..=9));
+/// ```
+impl<T: step::Step> Divisible for RangeInclusive<T>
+where
+    RangeInclusive<T>: Iterator,
+{
+    type Power = IndexedPower;
+    fn base_length(&self) -> usize {
+        if self.is_empty() {
+            0
+        } else {
+            T::distance(*self.start(), *self.end())
+                .checked_add(1)
+                .expect("range is too large")
+        }
+    }
+    fn divide(self) -> (Self, Self) {
+        let mid = self.base_length() / 2;
+        self.divide_at(mid)
+    }
+}
+
+impl<T: step::Step> DivisibleIntoBlocks for RangeInclusive<T>
+where
+    RangeInclusive<T>: Iterator,
+{
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        if index == 0 {
+            (empty_inclusive_range(*self.start()), self)
+        } else if index >= self.base_length() {
+            let end = *self.end();
+            (self, empty_inclusive_range(end))
+        } else {
+            let (start, end) = self.into_inner();
+            (
+                start..=T::forward(start, index - 1),
+                T::forward(start, index)..=end,
+            )
+        }
+    }
+}
+
+impl<T: step::Step> DivisibleAtIndex for RangeInclusive<T> where RangeInclusive<T>: Iterator {}
+
+/// Range without upper bound, for brute force searches.
+/// Its base length is `usize::MAX` and dividing it leaves a bounded range on the left
+/// and an unbounded one on the right.
+/// It is meant for early-exit operations on macro blocks (see `by_blocks`)
+/// which only ever divide it at block sizes.
+///
+/// # Example
+///
+/// ```
+/// use rayon_adaptive::prelude::*;
+/// use rayon_adaptive::InfiniteRange;
+/// let root = InfiniteRange::from(1u64..)
+///     .into_adapt_iter()
+///     .find_first(|&x| x * x > 1_000_000_000_000);
+/// assert_eq!(root, Some(1_000_001));
+/// ```
+pub struct InfiniteRange<T> {
+    start: T,
+    // None for no upper bound
+    length: Option<usize>,
+}
+
+impl<T> From<RangeFrom<T>> for InfiniteRange<T> {
+    fn from(range: RangeFrom<T>) -> Self {
+        InfiniteRange {
+            start: range.start,
+            length: None,
+        }
+    }
+}
+
+impl<T: step::Step> Divisible for InfiniteRange<T> {
+    type Power = IndexedPower;
+    fn base_length(&self) -> usize {
+        self.length.unwrap_or(usize::MAX)
+    }
+    fn divide(self) -> (Self, Self) {
+        let mid = self.base_length() / 2;
+        self.divide_at(mid)
+    }
+}
+
+impl<T: step::Step> DivisibleIntoBlocks for InfiniteRange<T> {
     fn divide_at(self, index: usize) -> (Self, Self) {
+        let right_length = self.length.map(|length| {
+            assert!(index <= length);
+            length - index
+        });
         (
-            self.start..(self.start + index),
-            (self.start + index)..self.end,
+            InfiniteRange {
+                start: self.start,
+                length: Some(index),
+            },
+            InfiniteRange {
+                start: T::forward(self.start, index),
+                length: right_length,
+            },
         )
     }
 }
 
-impl DivisibleAtIndex for Range<usize> {}
+impl<T: step::Step> DivisibleAtIndex for InfiniteRange<T> {}
+
+impl<T> IntoIterator for InfiniteRange<T>
+where
+    RangeFrom<T>: Iterator<Item = T>,
+{
+    type Item = T;
+    type IntoIter = Take<RangeFrom<T>>;
+    fn into_iter(self) -> Self::IntoIter {
+        (self.start..).take(self.length.unwrap_or(usize::MAX))
+    }
+}
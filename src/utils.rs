@@ -1,7 +1,9 @@
 //! Utilities functions to ease life of end users.
 use crate::prelude::*;
+use crate::DivisionError;
 use std;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::Arc;
 
 /// Fuse contiguous slices together back into one.
 /// This panics if slices are not contiguous.
@@ -21,6 +23,15 @@ pub fn powers(starting_value: usize) -> impl Iterator<Item = usize> {
     })
 }
 
+/// iterate on starting_value * growth**i, always increasing by at least one
+pub fn growing_sizes(starting_value: usize, growth: f64) -> impl Iterator<Item = usize> {
+    (0..).scan(starting_value, move |state, _| {
+        *state = std::cmp::max((*state as f64 * growth) as usize, *state + 1);
+        Some(*state)
+    })
+}
+
+/// Divisible which looks empty once its `abort` flag is set.
 pub struct AbortingDivisible<'a, I> {
     pub real_content: I,
     pub abort: &'a AtomicBool,
@@ -51,6 +62,15 @@ impl<'a, I: Divisible> Divisible for AbortingDivisible<'a, I> {
 }
 
 impl<'a, I: DivisibleIntoBlocks> DivisibleIntoBlocks for AbortingDivisible<'a, I> {
+    // we might get aborted between length computations and divisions
+    fn try_divide_at(self, index: usize) -> Result<(Self, Self), DivisionError> {
+        let length = self.real_content.base_length();
+        if index > length {
+            Err(DivisionError::IndexOutOfBounds { index, length })
+        } else {
+            Ok(self.divide_at(index))
+        }
+    }
     fn divide_at(self, index: usize) -> (Self, Self) {
         let (left, right) = self.real_content.divide_at(index);
         (
@@ -68,6 +88,8 @@ impl<'a, I: DivisibleIntoBlocks> DivisibleIntoBlocks for AbortingDivisible<'a, I
 
 impl<'a, I: DivisibleAtIndex> DivisibleAtIndex for AbortingDivisible<'a, I> {}
 
+impl<'a, I: AdaptiveIterator> AdaptiveIterator for AbortingDivisible<'a, I> {}
+
 impl<'a, I: IntoIterator> IntoIterator for AbortingDivisible<'a, I> {
     type IntoIter = I::IntoIter;
     type Item = I::Item;
@@ -75,3 +97,396 @@ impl<'a, I: IntoIterator> IntoIterator for AbortingDivisible<'a, I> {
         self.real_content.into_iter()
     }
 }
+
+/// Divisible which looks empty once something was found before its position.
+/// Positions are counted in base lengths and `found` holds the smallest
+/// position at which something was found.
+pub(crate) struct OrderedAbortingDivisible<'a, I> {
+    pub(crate) real_content: I,
+    pub(crate) position: usize,
+    pub(crate) found: &'a AtomicUsize,
+}
+
+impl<'a, I: Divisible> Divisible for OrderedAbortingDivisible<'a, I> {
+    type Power = I::Power;
+    fn base_length(&self) -> usize {
+        if self.found.load(Ordering::Relaxed) <= self.position {
+            0
+        } else {
+            self.real_content.base_length()
+        }
+    }
+    fn divide(self) -> (Self, Self) {
+        let (left, right) = self.real_content.divide();
+        let right_position = self.position + left.base_length();
+        (
+            OrderedAbortingDivisible {
+                real_content: left,
+                position: self.position,
+                found: self.found,
+            },
+            OrderedAbortingDivisible {
+                real_content: right,
+                position: right_position,
+                found: self.found,
+            },
+        )
+    }
+}
+
+impl<'a, I: DivisibleIntoBlocks> DivisibleIntoBlocks for OrderedAbortingDivisible<'a, I> {
+    // we might get cancelled between length computations and divisions
+    fn try_divide_at(self, index: usize) -> Result<(Self, Self), DivisionError> {
+        let length = self.real_content.base_length();
+        if index > length {
+            Err(DivisionError::IndexOutOfBounds { index, length })
+        } else {
+            Ok(self.divide_at(index))
+        }
+    }
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.real_content.divide_at(index);
+        let right_position = self.position + left.base_length();
+        (
+            OrderedAbortingDivisible {
+                real_content: left,
+                position: self.position,
+                found: self.found,
+            },
+            OrderedAbortingDivisible {
+                real_content: right,
+                position: right_position,
+                found: self.found,
+            },
+        )
+    }
+}
+
+impl<'a, I: IntoIterator> IntoIterator for OrderedAbortingDivisible<'a, I> {
+    type IntoIter = I::IntoIter;
+    type Item = I::Item;
+    fn into_iter(self) -> Self::IntoIter {
+        self.real_content.into_iter()
+    }
+}
+
+/// Divisible dividing where costs are balanced instead of at mid length.
+/// See `DivisibleIntoBlocks::with_cost`.
+pub struct Costed<I, C> {
+    pub(crate) real_content: I,
+    pub(crate) cost: C,
+    pub(crate) start: usize,
+}
+
+impl<I: DivisibleIntoBlocks, C: Fn(usize, usize) -> f64 + Send + Sync + Copy> Divisible
+    for Costed<I, C>
+{
+    type Power = I::Power;
+    fn base_length(&self) -> usize {
+        self.real_content.base_length()
+    }
+    fn divide(self) -> (Self, Self) {
+        let length = self.base_length();
+        if length < 2 {
+            return self.divide_at(length / 2);
+        }
+        // find smallest left part costing at least half
+        let half_cost = (self.cost)(length, self.start) / 2.0;
+        let (mut low, mut high) = (1, length - 1);
+        while low < high {
+            let middle = (low + high) / 2;
+            if (self.cost)(middle, self.start) < half_cost {
+                low = middle + 1;
+            } else {
+                high = middle;
+            }
+        }
+        self.divide_at(low)
+    }
+}
+
+impl<I: DivisibleIntoBlocks, C: Fn(usize, usize) -> f64 + Send + Sync + Copy> DivisibleIntoBlocks
+    for Costed<I, C>
+{
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.real_content.divide_at(index);
+        (
+            Costed {
+                real_content: left,
+                cost: self.cost,
+                start: self.start,
+            },
+            Costed {
+                real_content: right,
+                cost: self.cost,
+                start: self.start + index,
+            },
+        )
+    }
+}
+
+impl<I: DivisibleAtIndex, C: Fn(usize, usize) -> f64 + Send + Sync + Copy> DivisibleAtIndex
+    for Costed<I, C>
+{
+}
+
+impl<I: AdaptiveIterator, C: Fn(usize, usize) -> f64 + Send + Sync + Copy> AdaptiveIterator
+    for Costed<I, C>
+{
+}
+
+impl<I: IntoIterator, C> IntoIterator for Costed<I, C> {
+    type IntoIter = I::IntoIter;
+    type Item = I::Item;
+    fn into_iter(self) -> Self::IntoIter {
+        self.real_content.into_iter()
+    }
+}
+
+/// Divisible dividing at median weight instead of mid length.
+/// See `DivisibleIntoBlocks::with_weights`.
+pub struct Weighted<I> {
+    pub(crate) real_content: I,
+    // weight of all elements before each index, shared by all pieces
+    pub(crate) prefix_sums: Arc<Vec<f64>>,
+    pub(crate) start: usize,
+}
+
+impl<I: DivisibleIntoBlocks> Divisible for Weighted<I> {
+    type Power = I::Power;
+    fn base_length(&self) -> usize {
+        self.real_content.base_length()
+    }
+    fn divide(self) -> (Self, Self) {
+        let length = self.base_length();
+        if length < 2 {
+            return self.divide_at(length / 2);
+        }
+        // find smallest left part weighing at least half
+        let prefix_sums = &self.prefix_sums[self.start..=self.start + length];
+        let half_weight = (prefix_sums[0] + prefix_sums[length]) / 2.0;
+        // both sides stay non-empty, even if the last element weighs more than half
+        let index = prefix_sums[1..length].partition_point(|&sum| sum < half_weight) + 1;
+        self.divide_at(std::cmp::min(index, length - 1))
+    }
+}
+
+impl<I: DivisibleIntoBlocks> DivisibleIntoBlocks for Weighted<I> {
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.real_content.divide_at(index);
+        (
+            Weighted {
+                real_content: left,
+                prefix_sums: self.prefix_sums.clone(),
+                start: self.start,
+            },
+            Weighted {
+                real_content: right,
+                prefix_sums: self.prefix_sums,
+                start: self.start + index,
+            },
+        )
+    }
+}
+
+impl<I: DivisibleAtIndex> DivisibleAtIndex for Weighted<I> {}
+
+impl<I: AdaptiveIterator> AdaptiveIterator for Weighted<I> {}
+
+impl<I: IntoIterator> IntoIterator for Weighted<I> {
+    type IntoIter = I::IntoIter;
+    type Item = I::Item;
+    fn into_iter(self) -> Self::IntoIter {
+        self.real_content.into_iter()
+    }
+}
+
+/// Divisible with its sides flipped.
+/// See `Divisible::rev_divisible`.
+pub struct Rev<I> {
+    pub(crate) real_content: I,
+}
+
+impl<I> Rev<I> {
+    /// Return the input in its initial direction.
+    pub fn into_inner(self) -> I {
+        self.real_content
+    }
+}
+
+impl<I: Divisible> Divisible for Rev<I> {
+    type Power = I::Power;
+    fn base_length(&self) -> usize {
+        self.real_content.base_length()
+    }
+    fn divide(self) -> (Self, Self) {
+        let (left, right) = self.real_content.divide();
+        (
+            Rev {
+                real_content: right,
+            },
+            Rev { real_content: left },
+        )
+    }
+}
+
+impl<I: DivisibleIntoBlocks> DivisibleIntoBlocks for Rev<I> {
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        let length = self.base_length();
+        let (left, right) = self.real_content.divide_at(length - index);
+        (
+            Rev {
+                real_content: right,
+            },
+            Rev { real_content: left },
+        )
+    }
+}
+
+impl<I: DivisibleAtIndex> DivisibleAtIndex for Rev<I> {}
+
+impl<I: AdaptiveIterator> AdaptiveIterator for Rev<I> where I::IntoIter: DoubleEndedIterator {}
+impl<I: AdaptiveIndexedIterator> AdaptiveIndexedIterator for Rev<I> where
+    I::IntoIter: DoubleEndedIterator
+{
+}
+
+impl<I: IntoIterator> IntoIterator for Rev<I>
+where
+    I::IntoIter: DoubleEndedIterator,
+{
+    type IntoIter = std::iter::Rev<I::IntoIter>;
+    type Item = I::Item;
+    fn into_iter(self) -> Self::IntoIter {
+        self.real_content.into_iter().rev()
+    }
+}
+
+/// Divisible remembering the global index of its first element.
+/// See `Divisible::enumerate_divisible`.
+pub struct Enumerate<I> {
+    pub(crate) real_content: I,
+    pub(crate) offset: usize,
+}
+
+impl<I> Enumerate<I> {
+    /// Global index of our first element.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+    /// Return our offset and our content.
+    pub fn into_parts(self) -> (usize, I) {
+        (self.offset, self.real_content)
+    }
+}
+
+impl<I: Divisible> Divisible for Enumerate<I> {
+    type Power = I::Power;
+    fn base_length(&self) -> usize {
+        self.real_content.base_length()
+    }
+    fn divide(self) -> (Self, Self) {
+        let (left, right) = self.real_content.divide();
+        let right_offset = self.offset + left.base_length();
+        (
+            Enumerate {
+                real_content: left,
+                offset: self.offset,
+            },
+            Enumerate {
+                real_content: right,
+                offset: right_offset,
+            },
+        )
+    }
+}
+
+impl<I: DivisibleIntoBlocks> DivisibleIntoBlocks for Enumerate<I> {
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.real_content.divide_at(index);
+        let right_offset = self.offset + left.base_length();
+        (
+            Enumerate {
+                real_content: left,
+                offset: self.offset,
+            },
+            Enumerate {
+                real_content: right,
+                offset: right_offset,
+            },
+        )
+    }
+}
+
+impl<I: DivisibleAtIndex> DivisibleAtIndex for Enumerate<I> {}
+
+// indices are only right if base lengths count elements
+impl<I: AdaptiveIndexedIterator> AdaptiveIterator for Enumerate<I> {}
+impl<I: AdaptiveIndexedIterator> AdaptiveIndexedIterator for Enumerate<I> {}
+
+impl<I: IntoIterator> IntoIterator for Enumerate<I> {
+    type IntoIter = std::iter::Zip<std::ops::RangeFrom<usize>, I::IntoIter>;
+    type Item = (usize, I::Item);
+    fn into_iter(self) -> Self::IntoIter {
+        (self.offset..).zip(self.real_content)
+    }
+}
+
+/// Divisible keeping only every `step`-th element of its content.
+/// See `DivisibleAtIndex::step_by_divisible`.
+pub struct StepBy<I> {
+    pub(crate) real_content: I,
+    pub(crate) step: usize,
+}
+
+impl<I> StepBy<I> {
+    /// Return the whole underlying input.
+    pub fn into_inner(self) -> I {
+        self.real_content
+    }
+}
+
+impl<I: DivisibleIntoBlocks> Divisible for StepBy<I> {
+    type Power = I::Power;
+    fn base_length(&self) -> usize {
+        self.real_content.base_length().div_ceil(self.step)
+    }
+    fn divide(self) -> (Self, Self) {
+        let mid = self.base_length() / 2;
+        self.divide_at(mid)
+    }
+}
+
+impl<I: DivisibleIntoBlocks> DivisibleIntoBlocks for StepBy<I> {
+    fn divide_at(self, index: usize) -> (Self, Self) {
+        // right side must start on a kept element
+        let real_index = std::cmp::min(
+            index.saturating_mul(self.step),
+            self.real_content.base_length(),
+        );
+        let (left, right) = self.real_content.divide_at(real_index);
+        (
+            StepBy {
+                real_content: left,
+                step: self.step,
+            },
+            StepBy {
+                real_content: right,
+                step: self.step,
+            },
+        )
+    }
+}
+
+impl<I: DivisibleAtIndex> DivisibleAtIndex for StepBy<I> {}
+
+impl<I: AdaptiveIterator> AdaptiveIterator for StepBy<I> {}
+impl<I: AdaptiveIndexedIterator> AdaptiveIndexedIterator for StepBy<I> {}
+
+impl<I: IntoIterator> IntoIterator for StepBy<I> {
+    type IntoIter = std::iter::StepBy<I::IntoIter>;
+    type Item = I::Item;
+    fn into_iter(self) -> Self::IntoIter {
+        self.real_content.into_iter().step_by(self.step)
+    }
+}